@@ -1,7 +1,7 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote, ToTokens};
 use syn::Data;
 use syn::DeriveInput;
 use syn::Fields;
@@ -50,3 +50,504 @@ pub fn derive_is_same(input: TokenStream) -> TokenStream {
         panic!("derive(IsSame) can only be used with struct items")
     }
 }
+
+#[proc_macro_derive(Diff)]
+pub fn derive_diff(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).unwrap();
+    let name = &input.ident;
+    let vis = &input.vis;
+    let change_name = format_ident!("{}Change", name);
+    let change_doc = format!("The changes to a [`{}`].", name);
+
+    if let Data::Struct(data) = input.data {
+        let (change_struct, body) = match data.fields {
+            Fields::Named(fields) => {
+                let defs = fields.named.iter().map(|field| {
+                    let name = &field.ident;
+                    let vis = &field.vis;
+                    let ty = &field.ty;
+                    let doc = format!("The change to `{}`, if it changed.", name.as_ref().unwrap());
+                    quote! {
+                        #[doc = #doc]
+                        #vis #name: ::core::option::Option<<#ty as ::is_same::diff::Diff>::Change>
+                    }
+                });
+                let names = fields.named.iter().map(|field| &field.ident);
+                let diffs = names.clone().map(|name| {
+                    quote! {
+                        #name: ::is_same::diff::Diff::diff(&self.#name, &other.#name)
+                    }
+                });
+                let change_struct = quote! {
+                    #[doc = #change_doc]
+                    #vis struct #change_name {
+                        #(#defs),*
+                    }
+                };
+                let body = quote! {
+                    let change = #change_name {
+                        #(#diffs),*
+                    };
+                    if #(change.#names.is_none() &&)* true {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(change)
+                    }
+                };
+                (change_struct, body)
+            }
+            Fields::Unnamed(fields) => {
+                let defs = fields.unnamed.iter().map(|field| {
+                    let vis = &field.vis;
+                    let ty = &field.ty;
+                    quote! {
+                        #vis ::core::option::Option<<#ty as ::is_same::diff::Diff>::Change>
+                    }
+                });
+                let indices = (0..fields.unnamed.len()).map(Index::from);
+                let diffs = indices.clone().map(|index| {
+                    quote! {
+                        ::is_same::diff::Diff::diff(&self.#index, &other.#index)
+                    }
+                });
+                let change_struct = quote! {
+                    #[doc = #change_doc]
+                    #vis struct #change_name(#(#defs),*);
+                };
+                let body = quote! {
+                    let change = #change_name(#(#diffs),*);
+                    if #(change.#indices.is_none() &&)* true {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(change)
+                    }
+                };
+                (change_struct, body)
+            }
+            Fields::Unit => {
+                let change_struct = quote! {
+                    #[doc = #change_doc]
+                    #vis struct #change_name;
+                };
+                let body = quote! {
+                    let _ = other;
+                    ::core::option::Option::None
+                };
+                (change_struct, body)
+            }
+        };
+        let tokens = quote! {
+            #change_struct
+
+            impl ::is_same::diff::Diff for #name {
+                type Change = #change_name;
+
+                fn diff(&self, other: &Self) -> ::core::option::Option<#change_name> {
+                    #body
+                }
+            }
+        };
+        tokens.into()
+    } else {
+        panic!("derive(Diff) can only be used with struct items")
+    }
+}
+
+#[proc_macro_derive(FirstDifference)]
+pub fn derive_first_difference(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).unwrap();
+    let name = &input.ident;
+
+    if let Data::Struct(data) = input.data {
+        let checks = match data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .map(|field| {
+                    let name = &field.ident;
+                    let label = name.as_ref().unwrap().to_string();
+                    quote! {
+                        if let ::core::option::Option::Some(path) =
+                            ::is_same::first_difference::FirstDifference::first_difference(
+                                &self.#name,
+                                &other.#name,
+                            )
+                        {
+                            return ::core::option::Option::Some(path.prepend_field(#label));
+                        }
+                    }
+                })
+                .collect::<Vec<_>>(),
+            Fields::Unnamed(fields) => (0..fields.unnamed.len())
+                .map(|index| {
+                    let label = index.to_string();
+                    let index = Index::from(index);
+                    quote! {
+                        if let ::core::option::Option::Some(path) =
+                            ::is_same::first_difference::FirstDifference::first_difference(
+                                &self.#index,
+                                &other.#index,
+                            )
+                        {
+                            return ::core::option::Option::Some(path.prepend_field(#label));
+                        }
+                    }
+                })
+                .collect::<Vec<_>>(),
+            Fields::Unit => Vec::new(),
+        };
+        let tokens = quote! {
+            impl ::is_same::first_difference::FirstDifference for #name {
+                fn first_difference(
+                    &self,
+                    other: &Self,
+                ) -> ::core::option::Option<::is_same::first_difference::DiffPath> {
+                    let _ = other;
+                    #(#checks)*
+                    ::core::option::Option::None
+                }
+            }
+        };
+        tokens.into()
+    } else {
+        panic!("derive(FirstDifference) can only be used with struct items")
+    }
+}
+
+/// Implements both `TriviallySame` and `IsSame`, comparing values by their
+/// bytes. Use this instead of `derive(IsSame)`; every field has to be
+/// `TriviallySame` and the struct can't contain any padding.
+#[proc_macro_derive(TriviallySame)]
+pub fn derive_trivially_same(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).unwrap();
+    let name = &input.ident;
+
+    if let Data::Struct(data) = input.data {
+        let types = data
+            .fields
+            .iter()
+            .map(|field| &field.ty)
+            .collect::<Vec<_>>();
+        let tokens = quote! {
+            unsafe impl ::is_same::trivial::TriviallySame for #name
+            where
+                #(#types: ::is_same::trivial::TriviallySame,)*
+            {
+            }
+
+            const _: () = ::core::assert!(
+                ::core::mem::size_of::<#name>() == 0 #(+ ::core::mem::size_of::<#types>())*,
+                "derive(TriviallySame) can't be used with structs that contain padding"
+            );
+
+            impl ::is_same::IsSame for #name {
+                fn is_same(&self, other: &Self) -> bool {
+                    ::is_same::trivial::is_same_bytes(
+                        ::core::slice::from_ref(self),
+                        ::core::slice::from_ref(other),
+                    )
+                }
+
+                fn is_same_slice(left: &[Self], right: &[Self]) -> bool {
+                    ::is_same::trivial::is_same_bytes(left, right)
+                }
+            }
+        };
+        tokens.into()
+    } else {
+        panic!("derive(TriviallySame) can only be used with struct items")
+    }
+}
+
+#[proc_macro_derive(Visit)]
+pub fn derive_visit(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).unwrap();
+    let name = &input.ident;
+
+    if let Data::Struct(data) = input.data {
+        let fields = match data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .map(|field| {
+                    let name = &field.ident;
+                    let label = name.as_ref().unwrap().to_string();
+                    (label, quote! { #name })
+                })
+                .collect::<Vec<_>>(),
+            Fields::Unnamed(fields) => (0..fields.unnamed.len())
+                .map(|index| {
+                    let label = index.to_string();
+                    let index = Index::from(index);
+                    (label, quote! { #index })
+                })
+                .collect::<Vec<_>>(),
+            Fields::Unit => Vec::new(),
+        };
+        let visits = fields.iter().map(|(label, member)| {
+            quote! {
+                if !walker.field(#label, &self.#member, &other.#member) {
+                    same = false;
+                    if !walker.is_exhaustive() {
+                        return false;
+                    }
+                }
+            }
+        });
+        let tokens = quote! {
+            impl ::is_same::visit::Visit for #name {
+                fn visit(
+                    &self,
+                    other: &Self,
+                    walker: &mut ::is_same::visit::Walker<'_>,
+                ) -> bool {
+                    let _ = (other, &walker);
+                    #[allow(unused_mut)]
+                    let mut same = true;
+                    #(#visits)*
+                    same
+                }
+            }
+        };
+        tokens.into()
+    } else {
+        panic!("derive(Visit) can only be used with struct items")
+    }
+}
+
+#[proc_macro_derive(SameFingerprint)]
+pub fn derive_same_fingerprint(input: TokenStream) -> TokenStream {
+    derive_hash_fields(
+        input,
+        quote!(::is_same::fingerprint::SameFingerprint),
+        "write_fingerprint",
+        "SameFingerprint",
+    )
+}
+
+#[proc_macro_derive(SameHash)]
+pub fn derive_same_hash(input: TokenStream) -> TokenStream {
+    derive_hash_fields(
+        input,
+        quote!(::is_same::same_key::SameHash),
+        "same_hash",
+        "SameHash",
+    )
+}
+
+// SameFingerprint and SameHash have the same shape, a method that takes
+// a hasher, and both derives feed each field to it in order.
+fn derive_hash_fields(
+    input: TokenStream,
+    trait_path: impl ToTokens,
+    method: &str,
+    derive_name: &str,
+) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).unwrap();
+    let name = &input.ident;
+    let method = format_ident!("{}", method);
+
+    if let Data::Struct(data) = input.data {
+        let writes = match data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .map(|field| {
+                    let name = &field.ident;
+                    quote! {
+                        #trait_path::#method(&self.#name, state);
+                    }
+                })
+                .collect::<Vec<_>>(),
+            Fields::Unnamed(fields) => (0..fields.unnamed.len())
+                .map(|index| {
+                    let index = Index::from(index);
+                    quote! {
+                        #trait_path::#method(&self.#index, state);
+                    }
+                })
+                .collect::<Vec<_>>(),
+            Fields::Unit => Vec::new(),
+        };
+        let tokens = quote! {
+            impl #trait_path for #name {
+                fn #method<H>(&self, state: &mut H)
+                where
+                    H: ::core::hash::Hasher,
+                {
+                    let _ = &state;
+                    #(#writes)*
+                }
+            }
+        };
+        tokens.into()
+    } else {
+        panic!("derive({}) can only be used with struct items", derive_name)
+    }
+}
+
+/// Fields can't be named `none` or `all`, since their constants would
+/// clash with the built-in `NONE` and `ALL`:
+///
+/// ```compile_fail
+/// use is_same_derive::{ChangedFields, IsSame};
+///
+/// #[derive(IsSame, ChangedFields)]
+/// struct Filter {
+///     all: bool,
+/// }
+/// ```
+#[proc_macro_derive(ChangedFields)]
+pub fn derive_changed_fields(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).unwrap();
+    let name = &input.ident;
+    let vis = &input.vis;
+    let mask_name = format_ident!("{}Changed", name);
+    let mask_doc = format!(
+        "A set of fields of [`{}`], as returned by `changed_fields`.",
+        name
+    );
+
+    if let Data::Struct(data) = input.data {
+        if data.fields.len() > 64 {
+            panic!("derive(ChangedFields) only supports structs with up to 64 fields");
+        }
+        for ident in data.fields.iter().filter_map(|field| field.ident.as_ref()) {
+            let label = ident.to_string();
+            let label = label.strip_prefix("r#").unwrap_or(&label).to_uppercase();
+            if label == "NONE" || label == "ALL" {
+                let message = format!(
+                    "derive(ChangedFields) can't be used with a field named `{}`, since it \
+                     would clash with the built-in `{}` constant",
+                    ident, label
+                );
+                return syn::Error::new(ident.span(), message)
+                    .to_compile_error()
+                    .into();
+            }
+        }
+        let (consts, accessors): (Vec<_>, Vec<_>) = data
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| match &field.ident {
+                Some(ident) => {
+                    let label = ident.to_string();
+                    let label = label.strip_prefix("r#").unwrap_or(&label);
+                    (format_ident!("{}", label.to_uppercase()), quote!(#ident))
+                }
+                None => {
+                    let member = Index::from(index);
+                    (format_ident!("FIELD_{}", index), quote!(#member))
+                }
+            })
+            .unzip();
+        let docs = accessors
+            .iter()
+            .map(|accessor| format!("The `{}` field.", accessor));
+        let bits = (0..consts.len()).map(|index| 1u64 << index);
+        let all = (0..consts.len()).fold(0u64, |all, index| all | 1u64 << index);
+        let tokens = quote! {
+            #[doc = #mask_doc]
+            #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+            #vis struct #mask_name {
+                bits: u64,
+            }
+
+            impl #mask_name {
+                #(
+                    #[doc = #docs]
+                    pub const #consts: #mask_name = #mask_name { bits: #bits };
+                )*
+
+                /// No fields.
+                pub const NONE: #mask_name = #mask_name { bits: 0 };
+
+                /// Every field.
+                pub const ALL: #mask_name = #mask_name { bits: #all };
+
+                /// Returns the mask as an integer, with the first field as
+                /// the lowest bit.
+                pub const fn bits(self) -> u64 {
+                    self.bits
+                }
+
+                /// Creates a mask from an integer, ignoring bits that don't
+                /// correspond to a field.
+                pub const fn from_bits_truncate(bits: u64) -> #mask_name {
+                    #mask_name { bits: bits & #all }
+                }
+
+                /// Returns true if no fields are set.
+                pub const fn is_empty(self) -> bool {
+                    self.bits == 0
+                }
+
+                /// Returns true if every field in `other` is also set in
+                /// `self`.
+                pub const fn contains(self, other: #mask_name) -> bool {
+                    self.bits & other.bits == other.bits
+                }
+
+                /// Returns true if any field is set in both masks.
+                pub const fn intersects(self, other: #mask_name) -> bool {
+                    self.bits & other.bits != 0
+                }
+            }
+
+            impl ::core::ops::BitOr for #mask_name {
+                type Output = #mask_name;
+
+                fn bitor(self, other: #mask_name) -> #mask_name {
+                    #mask_name { bits: self.bits | other.bits }
+                }
+            }
+
+            impl ::core::ops::BitOrAssign for #mask_name {
+                fn bitor_assign(&mut self, other: #mask_name) {
+                    self.bits |= other.bits;
+                }
+            }
+
+            impl ::core::ops::BitAnd for #mask_name {
+                type Output = #mask_name;
+
+                fn bitand(self, other: #mask_name) -> #mask_name {
+                    #mask_name { bits: self.bits & other.bits }
+                }
+            }
+
+            impl ::core::ops::BitAndAssign for #mask_name {
+                fn bitand_assign(&mut self, other: #mask_name) {
+                    self.bits &= other.bits;
+                }
+            }
+
+            impl ::core::ops::Not for #mask_name {
+                type Output = #mask_name;
+
+                fn not(self) -> #mask_name {
+                    #mask_name { bits: !self.bits & #all }
+                }
+            }
+
+            impl ::is_same::changed_fields::ChangedFields for #name {
+                type Mask = #mask_name;
+
+                fn changed_fields(&self, other: &Self) -> #mask_name {
+                    let mut changed = #mask_name::NONE;
+                    #(
+                        if ::is_same::IsSame::is_not_same(&self.#accessors, &other.#accessors) {
+                            changed |= #mask_name::#consts;
+                        }
+                    )*
+                    let _ = other;
+                    changed
+                }
+            }
+        };
+        tokens.into()
+    } else {
+        panic!("derive(ChangedFields) can only be used with struct items")
+    }
+}
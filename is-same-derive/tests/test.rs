@@ -1,5 +1,16 @@
+use is_same::changed_fields::ChangedFields;
+use is_same::diff::Diff;
+use is_same::fingerprint::fingerprint;
+use is_same::first_difference::FirstDifference;
+use is_same::first_difference::PathSegment;
+use is_same::same_key::SameKey;
+use is_same::visit::{visit_all, visit_is_same, IsSameVisitor};
 use is_same::IsSame;
-use is_same_derive::IsSame;
+use is_same_derive::{
+    ChangedFields, Diff, FirstDifference, IsSame, SameFingerprint, SameHash, TriviallySame, Visit,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
 
 #[derive(IsSame)]
 struct MyCustomType {
@@ -38,3 +49,321 @@ fn test_cmp() {
 
     assert!(MyUnitStruct.is_same(&MyUnitStruct));
 }
+
+#[derive(IsSame, Diff)]
+struct Settings {
+    title: String,
+    sizes: Vec<u32>,
+    scale: f32,
+}
+
+#[derive(IsSame, Diff)]
+struct Pair(u8, u8);
+
+#[test]
+fn test_diff() {
+    let left = Settings {
+        title: "foo".to_owned(),
+        sizes: vec![1, 2],
+        scale: f32::NAN,
+    };
+    let mut right = Settings {
+        title: "foo".to_owned(),
+        sizes: vec![1, 2],
+        scale: f32::NAN,
+    };
+    assert!(left.diff(&right).is_none());
+    right.title = "bar".to_owned();
+    right.sizes.push(3);
+    let change: SettingsChange = left.diff(&right).unwrap();
+    assert_eq!(change.title, Some("bar".to_owned()));
+    assert_eq!(change.sizes.unwrap().changes.len(), 1);
+    assert!(change.scale.is_none());
+
+    assert!(Pair(1, 2).diff(&Pair(1, 2)).is_none());
+    let change = Pair(1, 2).diff(&Pair(1, 3)).unwrap();
+    assert_eq!((change.0, change.1), (None, Some(3)));
+}
+
+#[derive(IsSame, FirstDifference)]
+struct Pane {
+    title: String,
+}
+
+#[derive(IsSame, FirstDifference)]
+struct Layout {
+    panes: Vec<Pane>,
+    size: (u32, u32),
+}
+
+#[derive(IsSame, FirstDifference)]
+struct Size(u32, u32);
+
+#[test]
+fn test_first_difference() {
+    let pane = |title: &str| Pane {
+        title: title.to_owned(),
+    };
+    let left = Layout {
+        panes: vec![pane("a"), pane("b")],
+        size: (1, 2),
+    };
+    let right = Layout {
+        panes: vec![pane("a"), pane("c")],
+        size: (1, 2),
+    };
+    assert!(left.first_difference(&left).is_none());
+    let path = left.first_difference(&right).unwrap();
+    assert_eq!(path.to_string(), "panes[1].title");
+
+    let path = Size(1, 2).first_difference(&Size(1, 3)).unwrap();
+    assert_eq!(path.to_string(), "1");
+}
+
+#[derive(Clone, Copy, TriviallySame)]
+#[repr(C)]
+struct Particle {
+    position: [f32; 2],
+    velocity: [f32; 2],
+    age: u32,
+}
+
+#[derive(Clone, Copy, TriviallySame)]
+struct Id(u64);
+
+#[test]
+fn test_trivially_same() {
+    let particle = Particle {
+        position: [0.0, f32::NAN],
+        velocity: [1.0, -1.0],
+        age: 3,
+    };
+    assert!(particle.is_same(&particle));
+    let mut particles = vec![particle; 1000];
+    let copy = particles.clone();
+    assert!(particles.is_same(&copy));
+    particles[999].velocity[0] = -0.0;
+    assert!(particles.is_not_same(&copy));
+    particles[999].velocity[0] = 0.0;
+    assert!(particles.is_not_same(&copy));
+    particles[999].velocity[0] = 1.0;
+    assert!(particles.is_same(&copy));
+
+    let ids = [Id(1), Id(2)];
+    assert!(ids.is_same(&ids.clone()));
+    assert!(ids.is_not_same(&[Id(1), Id(ids[1].0 + 1)]));
+}
+
+#[derive(SameFingerprint)]
+struct Snapshot {
+    name: String,
+    values: Vec<f64>,
+}
+
+#[derive(SameFingerprint)]
+struct Wrapper(Snapshot, u8);
+
+#[derive(SameFingerprint)]
+struct Empty;
+
+#[test]
+fn test_same_fingerprint() {
+    let snapshot = || Snapshot {
+        name: "a".to_owned(),
+        values: vec![1.0, f64::NAN],
+    };
+    assert_eq!(fingerprint(&snapshot()), fingerprint(&snapshot()));
+    let mut changed = snapshot();
+    changed.values[0] = 2.0;
+    assert_ne!(fingerprint(&snapshot()), fingerprint(&changed));
+
+    assert_eq!(
+        fingerprint(&Wrapper(snapshot(), 1)),
+        fingerprint(&Wrapper(snapshot(), 1))
+    );
+    assert_ne!(
+        fingerprint(&Wrapper(snapshot(), 1)),
+        fingerprint(&Wrapper(snapshot(), 2))
+    );
+    assert_eq!(fingerprint(&Empty), fingerprint(&Empty));
+}
+
+#[derive(IsSame, ChangedFields)]
+struct Style {
+    color: u32,
+    width: f32,
+    r#type: &'static str,
+}
+
+#[derive(IsSame, ChangedFields)]
+struct Offset(i32, i32);
+
+#[test]
+fn test_changed_fields() {
+    let old = Style {
+        color: 0,
+        width: f32::NAN,
+        r#type: "solid",
+    };
+    let mut new = Style {
+        color: 0,
+        width: f32::NAN,
+        r#type: "solid",
+    };
+    assert!(old.changed_fields(&new).is_empty());
+    new.width = 1.0;
+    new.r#type = "dashed";
+    let changed = old.changed_fields(&new);
+    assert_eq!(changed, StyleChanged::WIDTH | StyleChanged::TYPE);
+    assert!(changed.contains(StyleChanged::WIDTH));
+    assert!(!changed.contains(StyleChanged::WIDTH | StyleChanged::COLOR));
+    assert!(changed.intersects(StyleChanged::TYPE | StyleChanged::COLOR));
+    assert_eq!(!changed, StyleChanged::COLOR);
+    assert_eq!(changed.bits(), 0b110);
+    assert_eq!(
+        StyleChanged::from_bits_truncate(u64::MAX),
+        StyleChanged::ALL
+    );
+
+    let mut dirty = StyleChanged::NONE;
+    dirty |= changed;
+    dirty &= StyleChanged::TYPE | StyleChanged::COLOR;
+    assert_eq!(dirty, StyleChanged::TYPE);
+
+    let changed = Offset(0, 0).changed_fields(&Offset(1, 1));
+    assert_eq!(changed & OffsetChanged::FIELD_0, OffsetChanged::FIELD_0);
+    assert_eq!(changed, OffsetChanged::ALL);
+}
+
+#[derive(IsSame, SameHash)]
+struct Query {
+    source: Rc<str>,
+    threshold: f64,
+}
+
+#[derive(IsSame, SameHash)]
+struct Scaled(Query, f32);
+
+#[test]
+fn test_same_hash() {
+    let source: Rc<str> = Rc::from("select");
+    let query = |threshold| Query {
+        source: source.clone(),
+        threshold,
+    };
+    let mut cache = HashMap::new();
+    cache.insert(SameKey(query(f64::NAN)), 1);
+    cache.insert(SameKey(query(0.0)), 2);
+    assert_eq!(cache.get(&SameKey(query(f64::NAN))), Some(&1));
+    assert_eq!(cache.get(&SameKey(query(-0.0))), None);
+    let other = Query {
+        source: Rc::from("select"),
+        threshold: 0.0,
+    };
+    assert_eq!(cache.get(&SameKey(other)), None);
+
+    let mut scaled = HashMap::new();
+    scaled.insert(SameKey(Scaled(query(1.0), 2.0)), ());
+    assert!(scaled.contains_key(&SameKey(Scaled(query(1.0), 2.0))));
+    assert!(!scaled.contains_key(&SameKey(Scaled(query(1.0), -2.0))));
+}
+
+#[derive(IsSame, Visit)]
+struct Widget {
+    name: String,
+    bounds: (f32, f32),
+    children: Vec<Widget>,
+}
+
+#[derive(IsSame, Visit)]
+struct Version(u32);
+
+#[derive(Default)]
+struct Log {
+    entered: usize,
+    left: usize,
+    mismatches: Vec<String>,
+}
+
+impl IsSameVisitor for Log {
+    fn enter(&mut self, _path: &[PathSegment]) {
+        self.entered += 1;
+    }
+
+    fn leave(&mut self, _path: &[PathSegment], _same: bool) {
+        self.left += 1;
+    }
+
+    fn mismatch(&mut self, path: &[PathSegment]) {
+        let path = path
+            .iter()
+            .map(|segment| match segment {
+                PathSegment::Field(name) => name.to_string(),
+                PathSegment::Index(index) => index.to_string(),
+                PathSegment::Key(key) => key.clone(),
+            })
+            .collect::<Vec<_>>();
+        self.mismatches.push(path.join("/"));
+    }
+}
+
+#[test]
+fn test_visit() {
+    let widget = |name: &str, x: f32, children| Widget {
+        name: name.to_owned(),
+        bounds: (x, f32::NAN),
+        children,
+    };
+    let left = widget(
+        "root",
+        0.0,
+        vec![widget("a", 1.0, vec![]), widget("b", 2.0, vec![])],
+    );
+    let right = widget(
+        "root",
+        -0.0,
+        vec![widget("a", 1.0, vec![]), widget("c", 2.0, vec![])],
+    );
+
+    let mut log = Log::default();
+    assert!(visit_is_same(&left, &left, &mut log));
+    assert!(log.mismatches.is_empty());
+    assert_eq!(log.entered, log.left);
+
+    let mut log = Log::default();
+    assert!(!visit_is_same(&left, &right, &mut log));
+    assert_eq!(log.mismatches, vec!["bounds/0"]);
+    assert_eq!(log.entered, log.left);
+
+    let mut log = Log::default();
+    assert!(!visit_all(&left, &right, &mut log));
+    assert_eq!(log.mismatches, vec!["bounds/0", "children/1/name"]);
+    assert_eq!(
+        left.is_same(&right),
+        visit_all(&left, &right, &mut Log::default())
+    );
+
+    let mut log = Log::default();
+    assert!(!visit_all(&Version(1), &Version(2), &mut log));
+    assert_eq!(log.mismatches, vec!["0"]);
+
+    let left = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let mut right = left;
+    right.15 = 0;
+    let mut log = Log::default();
+    assert!(!visit_all(&left, &right, &mut log));
+    assert_eq!(log.mismatches, vec!["15"]);
+
+    // Equal keys in different allocations aren't the same, so the key is
+    // both missing on the right and extra on the left.
+    let left = BTreeMap::from([(Rc::<str>::from("a"), 1)]);
+    let right = BTreeMap::from([(Rc::<str>::from("a"), 1)]);
+    let mut log = Log::default();
+    assert!(!visit_all(&left, &right, &mut log));
+    assert_eq!(log.mismatches, vec!["\"a\"", "\"a\""]);
+    let left = left.into_iter().collect::<HashMap<_, _>>();
+    let right = right.into_iter().collect::<HashMap<_, _>>();
+    let mut log = Log::default();
+    assert!(!visit_all(&left, &right, &mut log));
+    assert_eq!(log.mismatches, vec!["\"a\"", "\"a\""]);
+}
@@ -0,0 +1,247 @@
+//! Wrappers that change how a value is compared.
+//!
+//! These are meant to be used directly as struct fields, so that a
+//! derived IsSame impl picks up the wrapper's comparison instead of the
+//! default one for the wrapped type.
+
+use crate::IsSame;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+/// Compares what a pointer points to, instead of the pointer itself.
+///
+/// `Rc<T>` and `Arc<T>` are normally compared by address only, which
+/// assumes their contents never change. `Deep<Rc<T>>` compares the
+/// contents instead, for cases where that assumption doesn't hold, such
+/// as an `Arc<Stylesheet>` that gets rebuilt with the same contents.
+///
+/// Pointers to the same address are still the same without comparing
+/// their contents, so clones of the same `Rc` stay cheap to compare.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Deep<P>(pub P);
+
+impl<P> IsSame for Deep<P>
+where
+    P: Deref,
+    P::Target: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        core::ptr::eq(&*self.0, &*other.0) || (*self.0).is_same(&*other.0)
+    }
+}
+
+/// Compares any `PartialEq` type using `==`, for types that don't
+/// implement IsSame.
+///
+/// Note that this gives up the usual IsSame guarantees: a type whose
+/// `PartialEq` treats NaN as unequal to itself will never be the same.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ByEq<T>(pub T);
+
+impl<T> IsSame for ByEq<T>
+where
+    T: PartialEq,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+/// Compares a pointer by the address it points to, without looking at
+/// the contents.
+///
+/// This is what `Rc<T>` and `Arc<T>` already do, and can be used to get
+/// the same behavior for `Box<T>`, `&T`, or any other pointer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ByPtr<P>(pub P);
+
+impl<P> IsSame for ByPtr<P>
+where
+    P: Deref,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        core::ptr::eq(&*self.0, &*other.0)
+    }
+}
+
+macro_rules! wrapper_impls {
+    ($($name:ident),+) => {$(
+        impl<T> $name<T> {
+            /// Unwraps the value.
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> From<T> for $name<T> {
+            fn from(value: T) -> $name<T> {
+                $name(value)
+            }
+        }
+
+        impl<T> Deref for $name<T> {
+            type Target = T;
+
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $name<T> {
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+    )+};
+}
+
+wrapper_impls!(Deep, ByEq, ByPtr);
+
+/// The tolerance used by `Approx`.
+///
+/// Two values are within tolerance if the difference between them is no
+/// more than `ABSOLUTE`, or no more than `RELATIVE` times the larger of
+/// their magnitudes.
+pub trait Tolerance {
+    /// The largest absolute difference that is still the same.
+    const ABSOLUTE: f64;
+    /// The largest difference relative to the values being compared
+    /// that is still the same.
+    const RELATIVE: f64;
+}
+
+/// The default tolerance for `Approx`: values are the same if they're
+/// within `1e-6` of each other, in absolute or relative terms.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DefaultTolerance;
+
+impl Tolerance for DefaultTolerance {
+    const ABSOLUTE: f64 = 1e-6;
+    const RELATIVE: f64 = 1e-6;
+}
+
+/// Compares floats with a tolerance, instead of by their bit patterns.
+///
+/// Values with identical bit patterns are always the same, so NaN is
+/// still the same as itself, and the same infinities match. Otherwise
+/// NaN is never the same as anything. The tolerance is given by a type
+/// implementing `Tolerance`:
+///
+/// ```
+/// use is_same::adapters::{Approx, Tolerance};
+/// use is_same::IsSame;
+///
+/// struct Millimeter;
+///
+/// impl Tolerance for Millimeter {
+///     const ABSOLUTE: f64 = 0.001;
+///     const RELATIVE: f64 = 0.0;
+/// }
+///
+/// let left = Approx::<f32, Millimeter>::new(1.0);
+/// assert!(left.is_same(&Approx::new(1.0005)));
+/// assert!(left.is_not_same(&Approx::new(1.1)));
+/// ```
+///
+/// Keep in mind that comparing with a tolerance isn't transitive: `a`
+/// can be the same as `b` and `b` the same as `c` without `a` being the
+/// same as `c`, so slow drift can go unnoticed if each value is only
+/// compared against the previous one.
+#[repr(transparent)]
+pub struct Approx<T, Tol = DefaultTolerance>(pub T, PhantomData<Tol>);
+
+impl<T, Tol> Approx<T, Tol> {
+    /// Wraps a value.
+    pub fn new(value: T) -> Approx<T, Tol> {
+        Approx(value, PhantomData)
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+// Written by hand so that `Tol` doesn't need to implement these traits.
+impl<T, Tol> Clone for Approx<T, Tol>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Approx::new(self.0.clone())
+    }
+}
+
+impl<T, Tol> Copy for Approx<T, Tol> where T: Copy {}
+
+impl<T, Tol> fmt::Debug for Approx<T, Tol>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Approx").field(&self.0).finish()
+    }
+}
+
+impl<T, Tol> Default for Approx<T, Tol>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Approx::new(T::default())
+    }
+}
+
+impl<T, Tol> From<T> for Approx<T, Tol> {
+    fn from(value: T) -> Self {
+        Approx::new(value)
+    }
+}
+
+impl<T, Tol> Deref for Approx<T, Tol> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, Tol> DerefMut for Approx<T, Tol> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+fn within_tolerance<Tol>(left: f64, right: f64) -> bool
+where
+    Tol: Tolerance,
+{
+    // Infinities and NaN give an infinite or NaN difference, which is
+    // never within tolerance, even relative to an infinite magnitude.
+    let difference = (left - right).abs();
+    difference.is_finite()
+        && (difference <= Tol::ABSOLUTE
+            || difference <= Tol::RELATIVE * left.abs().max(right.abs()))
+}
+
+impl<Tol> IsSame for Approx<f32, Tol>
+where
+    Tol: Tolerance,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.0.is_same(&other.0) || within_tolerance::<Tol>(self.0.into(), other.0.into())
+    }
+}
+
+impl<Tol> IsSame for Approx<f64, Tol>
+where
+    Tol: Tolerance,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.0.is_same(&other.0) || within_tolerance::<Tol>(self.0, other.0)
+    }
+}
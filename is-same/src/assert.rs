@@ -0,0 +1,89 @@
+//! Assertion macros, exported from the crate root.
+
+/// Asserts that two values are the same according to IsSame.
+///
+/// On failure, the panic message includes both values if they implement
+/// `Debug`, and the path to the first difference if they implement
+/// `FirstDifference`. Like `assert_eq!`, a format string and arguments
+/// can be passed after the values to add to the message.
+///
+/// ```
+/// use is_same::assert_same;
+///
+/// assert_same!(f32::NAN, f32::NAN);
+/// assert_same!(vec![1, 2], [1, 2], "vecs differ for {}", "reasons");
+/// ```
+#[macro_export]
+macro_rules! assert_same {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_same!(@check $left, $right, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::assert_same!(
+            @check $left,
+            $right,
+            ::core::option::Option::Some(::core::format_args!($($arg)+))
+        )
+    };
+    (@check $left:expr, $right:expr, $args:expr) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::IsSame::is_same(left, right) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{
+                        ViaDebug, ViaFirstDifference, ViaNoDebug, ViaNoFirstDifference,
+                    };
+                    $crate::__private::assert_failed(
+                        "is_same",
+                        (&$crate::__private::Probe(left)).__debug(),
+                        (&$crate::__private::Probe(right)).__debug(),
+                        (&$crate::__private::PairProbe(left, right)).__first_difference(),
+                        $args,
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two values are not the same according to IsSame.
+///
+/// On failure, the panic message includes both values if they implement
+/// `Debug`. A format string and arguments can be passed after the
+/// values, like with `assert_ne!`.
+///
+/// ```
+/// use is_same::assert_not_same;
+///
+/// assert_not_same!(0.0, -0.0);
+/// ```
+#[macro_export]
+macro_rules! assert_not_same {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_not_same!(@check $left, $right, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::assert_not_same!(
+            @check $left,
+            $right,
+            ::core::option::Option::Some(::core::format_args!($($arg)+))
+        )
+    };
+    (@check $left:expr, $right:expr, $args:expr) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if $crate::IsSame::is_same(left, right) {
+                    #[allow(unused_imports)]
+                    use $crate::__private::{ViaDebug, ViaNoDebug};
+                    $crate::__private::assert_failed(
+                        "is_not_same",
+                        (&$crate::__private::Probe(left)).__debug(),
+                        (&$crate::__private::Probe(right)).__debug(),
+                        ::core::option::Option::None,
+                        $args,
+                    );
+                }
+            }
+        }
+    };
+}
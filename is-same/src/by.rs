@@ -0,0 +1,87 @@
+//! Comparing values with a closure instead of an IsSame impl.
+//!
+//! These are for one-off comparisons of types that don't implement
+//! IsSame, or that need to be compared differently than usual, without
+//! writing a newtype for them.
+//!
+//! ```
+//! use is_same::by::{same_by_key, slice_same_by_key};
+//!
+//! struct Entry {
+//!     id: u32,
+//!     weight: f32,
+//! }
+//!
+//! let left = Entry { id: 1, weight: f32::NAN };
+//! let right = Entry { id: 1, weight: f32::NAN };
+//! assert!(same_by_key(&left, &right, |entry| entry.weight));
+//!
+//! let other = [Entry { id: 1, weight: 0.0 }];
+//! assert!(slice_same_by_key(&[left], &other, |entry| entry.id));
+//! ```
+
+use crate::IsSame;
+
+/// Compares two values with the given function.
+pub fn same_by<'a, T, U, F>(left: &'a T, right: &'a U, is_same: F) -> bool
+where
+    T: ?Sized,
+    U: ?Sized,
+    F: FnOnce(&'a T, &'a U) -> bool,
+{
+    is_same(left, right)
+}
+
+/// Compares the keys extracted from two values with IsSame.
+pub fn same_by_key<'a, T, K, F>(left: &'a T, right: &'a T, mut key: F) -> bool
+where
+    T: ?Sized,
+    K: IsSame,
+    F: FnMut(&'a T) -> K,
+{
+    key(left).is_same(&key(right))
+}
+
+/// Compares two options with the given function. Two `None`s are the
+/// same, and the function is only called when both are `Some`.
+pub fn option_same_by<'a, T, U, F>(left: &'a Option<T>, right: &'a Option<U>, is_same: F) -> bool
+where
+    F: FnOnce(&'a T, &'a U) -> bool,
+{
+    match (left, right) {
+        (Some(left), Some(right)) => is_same(left, right),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Compares the keys extracted from two options with IsSame.
+pub fn option_same_by_key<'a, T, K, F>(left: &'a Option<T>, right: &'a Option<T>, key: F) -> bool
+where
+    K: IsSame,
+    F: FnMut(&'a T) -> K,
+{
+    option_same_by(left, right, |left, right| same_by_key(left, right, key))
+}
+
+/// Compares two slices element by element with the given function.
+pub fn slice_same_by<'a, T, U, F>(left: &'a [T], right: &'a [U], mut is_same: F) -> bool
+where
+    F: FnMut(&'a T, &'a U) -> bool,
+{
+    left.len() == right.len()
+        && left
+            .iter()
+            .zip(right.iter())
+            .all(|(left, right)| is_same(left, right))
+}
+
+/// Compares the keys extracted from the elements of two slices with
+/// IsSame.
+pub fn slice_same_by_key<'a, T, K, F>(left: &'a [T], right: &'a [T], mut key: F) -> bool
+where
+    K: IsSame,
+    F: FnMut(&'a T) -> K,
+{
+    slice_same_by(left, right, |left, right| key(left).is_same(&key(right)))
+}
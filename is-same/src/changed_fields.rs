@@ -0,0 +1,43 @@
+//! Finding which fields of a struct changed, as a bitmask.
+//!
+//! `#[derive(ChangedFields)]` from is-same-derive generates a
+//! `<Name>Changed` type with a constant for each field, such as
+//! `StyleChanged::COLOR`, which supports `|`, `&` and `!` like a set of
+//! bit flags. Comparing two values then gives a mask of the fields that
+//! aren't the same, which can be checked against a mask of the fields a
+//! particular step depends on:
+//!
+//! ```rs
+//! use is_same::changed_fields::ChangedFields;
+//! use is_same_derive::{ChangedFields, IsSame};
+//!
+//! #[derive(IsSame, ChangedFields)]
+//! struct Style {
+//!     color: u32,
+//!     width: f32,
+//!     label: String,
+//! }
+//!
+//! let old = Style { color: 0, width: 1.0, label: "a".to_owned() };
+//! let new = Style { color: 0, width: 2.0, label: "b".to_owned() };
+//! let changed = old.changed_fields(&new);
+//! assert_eq!(changed, StyleChanged::WIDTH | StyleChanged::LABEL);
+//! assert!(changed.intersects(StyleChanged::WIDTH | StyleChanged::COLOR));
+//! assert!(!changed.contains(StyleChanged::COLOR));
+//! ```
+
+/// Compares each field of a struct separately.
+///
+/// The derive supports structs with up to 64 fields, and adds the
+/// `<Name>Changed` mask type next to the struct. Fields can't be named
+/// `none` or `all`, since those constants are taken by the built-in
+/// `NONE` and `ALL` masks.
+pub trait ChangedFields {
+    /// The set of fields, with one bit per field.
+    type Mask;
+
+    /// Returns the fields whose values aren't the same in `self` and
+    /// `other`. The mask is empty exactly when all the fields are the
+    /// same.
+    fn changed_fields(&self, other: &Self) -> Self::Mask;
+}
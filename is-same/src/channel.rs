@@ -0,0 +1,121 @@
+//! Channel senders that skip values that haven't changed.
+
+use crate::IsSame;
+use std::sync::mpsc::{self, SendError};
+
+/// The sending half of a channel that `DedupSender` can wrap.
+///
+/// This is implemented for the std mpsc senders, and for
+/// `tokio::sync::watch::Sender` with the `tokio` feature.
+pub trait ChannelSender<T> {
+    /// The error returned when the value couldn't be sent, usually
+    /// because the receiver was dropped.
+    type Error;
+
+    /// Sends a value on the channel.
+    fn send(&self, value: T) -> Result<(), Self::Error>;
+}
+
+impl<T> ChannelSender<T> for mpsc::Sender<T> {
+    type Error = SendError<T>;
+
+    fn send(&self, value: T) -> Result<(), SendError<T>> {
+        mpsc::Sender::send(self, value)
+    }
+}
+
+/// Blocks while the channel is full, like `SyncSender::send`.
+impl<T> ChannelSender<T> for mpsc::SyncSender<T> {
+    type Error = SendError<T>;
+
+    fn send(&self, value: T) -> Result<(), SendError<T>> {
+        mpsc::SyncSender::send(self, value)
+    }
+}
+
+/// Replaces the value held by the channel and notifies the receivers.
+/// Like `watch::Sender::send`, this fails without storing the value once
+/// every receiver has been dropped.
+#[cfg(feature = "tokio")]
+impl<T> ChannelSender<T> for tokio::sync::watch::Sender<T> {
+    type Error = tokio::sync::watch::error::SendError<T>;
+
+    fn send(&self, value: T) -> Result<(), Self::Error> {
+        tokio::sync::watch::Sender::send(self, value)
+    }
+}
+
+/// Wraps a channel sender, dropping values that are the same as the last
+/// value that was sent.
+///
+/// A clone of each value that's sent is kept to compare the next one
+/// against, so this works best with values that are cheap to clone, like
+/// `Arc`s of snapshots.
+///
+/// ```
+/// use is_same::channel::DedupSender;
+/// use std::sync::mpsc;
+///
+/// let (sender, receiver) = mpsc::channel();
+/// let mut sender = DedupSender::new(sender);
+/// for value in &[1, 1, 2, 2, 1] {
+///     sender.send(*value).unwrap();
+/// }
+/// assert_eq!(receiver.try_iter().collect::<Vec<_>>(), [1, 2, 1]);
+/// ```
+#[derive(Debug)]
+pub struct DedupSender<T, S = mpsc::Sender<T>> {
+    sender: S,
+    last_sent: Option<T>,
+}
+
+impl<T, S> DedupSender<T, S>
+where
+    T: IsSame + Clone,
+    S: ChannelSender<T>,
+{
+    /// Wraps `sender`. The first value is always sent.
+    pub fn new(sender: S) -> DedupSender<T, S> {
+        DedupSender {
+            sender,
+            last_sent: None,
+        }
+    }
+
+    /// Sends `value` unless it's the same as the last value that was
+    /// sent. Returns whether it was sent.
+    ///
+    /// If sending fails, the last value is left alone, so the next send
+    /// is compared against the last value that actually made it.
+    pub fn send(&mut self, value: T) -> Result<bool, S::Error> {
+        if let Some(last_sent) = &self.last_sent {
+            if last_sent.is_same(&value) {
+                return Ok(false);
+            }
+        }
+        let copy = value.clone();
+        self.sender.send(value)?;
+        self.last_sent = Some(copy);
+        Ok(true)
+    }
+
+    /// Returns the last value that was sent.
+    pub fn last_sent(&self) -> Option<&T> {
+        self.last_sent.as_ref()
+    }
+
+    /// Forgets the last value, so that the next value is always sent.
+    pub fn reset(&mut self) {
+        self.last_sent = None;
+    }
+
+    /// Returns the wrapped sender.
+    pub fn get_ref(&self) -> &S {
+        &self.sender
+    }
+
+    /// Unwraps the sender.
+    pub fn into_inner(self) -> S {
+        self.sender
+    }
+}
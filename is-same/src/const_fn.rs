@@ -0,0 +1,65 @@
+//! Comparisons that can be used in const contexts.
+//!
+//! Trait methods can't be called from a `const fn` on stable Rust, so
+//! this module has a free function for each primitive type instead, plus
+//! one for slices of it. They follow the same rules as the IsSame impls,
+//! so floats are compared by their bit patterns.
+//!
+//! ```
+//! use is_same::const_fn::{is_same_f32, is_same_f32_slice};
+//!
+//! const TABLE: [f32; 3] = [0.0, 0.5, f32::NAN];
+//! const _: () = assert!(is_same_f32_slice(&TABLE, &[0.0, 0.5, f32::NAN]));
+//! const _: () = assert!(!is_same_f32(0.0, -0.0));
+//! ```
+//!
+//! Comparing floats in a const fn needs Rust 1.83 or newer, which is why
+//! this module is behind the `const_fn` feature.
+
+macro_rules! const_fn_impl {
+    ($($ty:ident => $value:ident, $slice:ident, |$left:ident, $right:ident| $same:expr;)+) => {$(
+        #[doc = concat!("Returns true if the two `", stringify!($ty), "`s are the same.")]
+        pub const fn $value($left: $ty, $right: $ty) -> bool {
+            $same
+        }
+
+        #[doc = concat!("Returns true if the two slices of `", stringify!($ty), "` are the same.")]
+        pub const fn $slice(left: &[$ty], right: &[$ty]) -> bool {
+            if left.len() != right.len() {
+                return false;
+            }
+            let mut i = 0;
+            while i < left.len() {
+                if !$value(left[i], right[i]) {
+                    return false;
+                }
+                i += 1;
+            }
+            true
+        }
+    )+};
+}
+
+const_fn_impl! {
+    u8 => is_same_u8, is_same_u8_slice, |left, right| left == right;
+    u16 => is_same_u16, is_same_u16_slice, |left, right| left == right;
+    u32 => is_same_u32, is_same_u32_slice, |left, right| left == right;
+    u64 => is_same_u64, is_same_u64_slice, |left, right| left == right;
+    u128 => is_same_u128, is_same_u128_slice, |left, right| left == right;
+    usize => is_same_usize, is_same_usize_slice, |left, right| left == right;
+    i8 => is_same_i8, is_same_i8_slice, |left, right| left == right;
+    i16 => is_same_i16, is_same_i16_slice, |left, right| left == right;
+    i32 => is_same_i32, is_same_i32_slice, |left, right| left == right;
+    i64 => is_same_i64, is_same_i64_slice, |left, right| left == right;
+    i128 => is_same_i128, is_same_i128_slice, |left, right| left == right;
+    isize => is_same_isize, is_same_isize_slice, |left, right| left == right;
+    bool => is_same_bool, is_same_bool_slice, |left, right| left == right;
+    char => is_same_char, is_same_char_slice, |left, right| left == right;
+    f32 => is_same_f32, is_same_f32_slice, |left, right| left.to_bits() == right.to_bits();
+    f64 => is_same_f64, is_same_f64_slice, |left, right| left.to_bits() == right.to_bits();
+}
+
+/// Returns true if the two strings are the same.
+pub const fn is_same_str(left: &str, right: &str) -> bool {
+    is_same_u8_slice(left.as_bytes(), right.as_bytes())
+}
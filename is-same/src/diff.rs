@@ -0,0 +1,261 @@
+//! Structured descriptions of what changed between two values.
+//!
+//! IsSame only says whether something changed. The `Diff` trait goes a
+//! step further and describes the change, which can be used to drive
+//! partial updates instead of rebuilding everything.
+//!
+//! `self.diff(other)` always describes how to get from `self` to
+//! `other`, and returns `None` exactly when `self.is_same(other)`.
+
+use crate::IsSame;
+
+#[cfg(feature = "alloc")]
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::map_delta::Map;
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+/// Describes the change between two versions of a value.
+///
+/// This can be derived for structs with `is-same-derive`. The derive
+/// generates a `<Name>Change` struct with the same fields, where each
+/// field holds an `Option` of that field's change.
+pub trait Diff: IsSame {
+    /// The description of a change.
+    type Change;
+
+    /// Returns what changed between `self` and `other`, or `None` if
+    /// they're the same.
+    fn diff(&self, other: &Self) -> Option<Self::Change>;
+}
+
+macro_rules! value_diff {
+    ($($name:ty),+) => {$(
+        /// The change is the new value.
+        impl Diff for $name {
+            type Change = $name;
+
+            fn diff(&self, other: &Self) -> Option<Self::Change> {
+                if self.is_same(other) {
+                    None
+                } else {
+                    Some(other.clone())
+                }
+            }
+        }
+    )+};
+}
+
+value_diff!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+value_diff!(f32, f64, bool, char);
+
+#[cfg(feature = "alloc")]
+value_diff!(String);
+
+/// The change to an element of a `Vec`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ElementChange<T>
+where
+    T: Diff,
+{
+    /// The element exists on both sides but was changed.
+    Modified(T::Change),
+    /// The element was added to the end.
+    Added(T),
+    /// The element was removed from the end.
+    Removed,
+}
+
+/// The changes between two `Vec`s, compared index by index.
+///
+/// Elements at the same index are diffed against each other, and
+/// anything past the end of the shorter side is added or removed. This
+/// means that inserting an element at the front shows up as every
+/// element being modified.
+#[cfg(feature = "alloc")]
+pub struct VecChange<T>
+where
+    T: Diff,
+{
+    /// The index and change of each element that changed, in order.
+    pub changes: Vec<(usize, ElementChange<T>)>,
+}
+
+// Written by hand since the derives don't add bounds for `T::Change`
+// when it's nested inside another type.
+#[cfg(feature = "alloc")]
+impl<T> Clone for VecChange<T>
+where
+    T: Diff + Clone,
+    T::Change: Clone,
+{
+    fn clone(&self) -> Self {
+        VecChange {
+            changes: self.changes.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> fmt::Debug for VecChange<T>
+where
+    T: Diff + fmt::Debug,
+    T::Change: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VecChange")
+            .field("changes", &self.changes)
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> PartialEq for VecChange<T>
+where
+    T: Diff + PartialEq,
+    T::Change: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.changes == other.changes
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Diff for Vec<T>
+where
+    T: Diff + Clone,
+{
+    type Change = VecChange<T>;
+
+    fn diff(&self, other: &Self) -> Option<Self::Change> {
+        let mut changes = Vec::new();
+        for (index, (left, right)) in self.iter().zip(other.iter()).enumerate() {
+            if let Some(change) = left.diff(right) {
+                changes.push((index, ElementChange::Modified(change)));
+            }
+        }
+        for (index, value) in other.iter().enumerate().skip(self.len()) {
+            changes.push((index, ElementChange::Added(value.clone())));
+        }
+        for index in other.len()..self.len() {
+            changes.push((index, ElementChange::Removed));
+        }
+
+        if changes.is_empty() {
+            None
+        } else {
+            Some(VecChange { changes })
+        }
+    }
+}
+
+/// The changes between two maps.
+///
+/// Entries are sorted by key for `BTreeMap`, and are in no particular
+/// order for `HashMap`. Keys are matched up with IsSame, so a key that's
+/// equal to one on the other side but not the same counts as removed and
+/// added.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChangeSet<Key, Value>
+where
+    Value: Diff,
+{
+    /// Entries that only exist in the new map.
+    pub added: Vec<(Key, Value)>,
+    /// Keys that only exist in the old map.
+    pub removed: Vec<Key>,
+    /// Keys that exist in both maps, with the change to their value.
+    pub modified: Vec<(Key, Value::Change)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<Key, Value> ChangeSet<Key, Value>
+where
+    Value: Diff,
+{
+    fn new() -> Self {
+        ChangeSet {
+            added: Vec::new(),
+            removed: Vec::new(),
+            modified: Vec::new(),
+        }
+    }
+
+    /// Returns true if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+
+    fn into_option(self) -> Option<Self> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self)
+        }
+    }
+}
+
+// Shared by the map impls.
+#[cfg(feature = "alloc")]
+fn diff_map<M>(left: &M, right: &M) -> Option<ChangeSet<M::Key, M::Value>>
+where
+    M: Map,
+    M::Key: IsSame + Clone,
+    M::Value: Diff + Clone,
+{
+    let mut changes = ChangeSet::new();
+    for (key, left_value) in left.entries() {
+        match right.lookup(key) {
+            Some((right_key, right_value)) if key.is_same(right_key) => {
+                if let Some(change) = left_value.diff(right_value) {
+                    changes.modified.push((key.clone(), change));
+                }
+            }
+            _ => changes.removed.push(key.clone()),
+        }
+    }
+    for (key, right_value) in right.entries() {
+        match left.lookup(key) {
+            Some((left_key, _)) if left_key.is_same(key) => (),
+            _ => changes.added.push((key.clone(), right_value.clone())),
+        }
+    }
+    changes.into_option()
+}
+
+#[cfg(feature = "alloc")]
+impl<Key, Value> Diff for BTreeMap<Key, Value>
+where
+    Key: IsSame + Ord + Clone,
+    Value: Diff + Clone,
+{
+    type Change = ChangeSet<Key, Value>;
+
+    fn diff(&self, other: &Self) -> Option<Self::Change> {
+        diff_map(self, other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Key, Value, State> Diff for HashMap<Key, Value, State>
+where
+    Key: IsSame + Eq + Hash + Clone,
+    Value: Diff + Clone,
+    State: BuildHasher,
+{
+    type Change = ChangeSet<Key, Value>;
+
+    fn diff(&self, other: &Self) -> Option<Self::Change> {
+        diff_map(self, other)
+    }
+}
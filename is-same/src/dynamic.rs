@@ -0,0 +1,89 @@
+//! Comparing trait objects.
+//!
+//! `IsSame` is generic over the type being compared against, so it
+//! can't be called through a trait object. `IsSameDyn` is an object
+//! safe version of it, implemented for every `IsSame + Any` type, and
+//! `dyn IsSameDyn` implements IsSame itself. Values of different
+//! concrete types are never the same.
+//!
+//! A trait can use `IsSameDyn` as a supertrait to make its trait
+//! objects comparable:
+//!
+//! ```
+//! use is_same::dynamic::IsSameDyn;
+//! use is_same::IsSame;
+//!
+//! trait Widget: IsSameDyn {}
+//!
+//! impl Widget for String {}
+//! impl Widget for f32 {}
+//!
+//! let left: Box<dyn Widget> = Box::new("foo".to_owned());
+//! let right: Box<dyn Widget> = Box::new(1.0);
+//! assert!(left.as_is_same_dyn().is_same(left.as_is_same_dyn()));
+//! assert!(left.as_is_same_dyn().is_not_same(right.as_is_same_dyn()));
+//! ```
+
+use crate::IsSame;
+use core::any::Any;
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+/// An object safe version of IsSame.
+pub trait IsSameDyn: Any {
+    /// Returns true if `other` has the same concrete type as `self` and
+    /// is the same according to its IsSame impl.
+    fn is_same_dyn(&self, other: &dyn Any) -> bool;
+
+    /// Returns `self` as `&dyn Any`.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns `self` as `&dyn IsSameDyn`, which is useful for getting
+    /// at the IsSame impl from a trait object of a subtrait.
+    fn as_is_same_dyn(&self) -> &dyn IsSameDyn;
+}
+
+impl<T> IsSameDyn for T
+where
+    T: IsSame + Any,
+{
+    fn is_same_dyn(&self, other: &dyn Any) -> bool {
+        other
+            .downcast_ref::<T>()
+            .is_some_and(|other| self.is_same(other))
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_is_same_dyn(&self) -> &dyn IsSameDyn {
+        self
+    }
+}
+
+// `Rc<dyn IsSameDyn>` and `Arc<dyn IsSameDyn>` are already covered by
+// the pointer comparison impls for `Rc<T>` and `Arc<T>`.
+macro_rules! dyn_impl {
+    ($($ty:ty),+) => {$(
+        impl IsSame for $ty {
+            fn is_same(&self, other: &Self) -> bool {
+                self.is_same_dyn(other.as_any())
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl IsSame for Box<$ty> {
+            fn is_same(&self, other: &Self) -> bool {
+                (**self).is_same(&**other)
+            }
+        }
+    )+};
+}
+
+dyn_impl!(
+    dyn IsSameDyn,
+    dyn IsSameDyn + Send,
+    dyn IsSameDyn + Send + Sync
+);
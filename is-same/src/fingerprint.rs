@@ -0,0 +1,150 @@
+//! Hashes that follow the same rules as IsSame.
+//!
+//! A fingerprint lets you check whether a value changed without keeping
+//! the old value around, for example by storing it next to a saved
+//! snapshot. Values that are the same always have the same fingerprint,
+//! and values with equal fingerprints are almost certainly the same.
+//!
+//! Fingerprints are computed with FNV-1a and always write integers in
+//! little endian order, so they're stable between runs and platforms,
+//! unlike `std::hash::DefaultHasher`.
+//!
+//! Pointer identity can't be part of a fingerprint, since addresses
+//! change between processes. `Rc` and `Arc` hash their contents instead,
+//! so two separately allocated `Rc`s with equal contents have the same
+//! fingerprint even though they aren't the same.
+
+use core::hash::Hasher;
+
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+/// Hashes a value consistently with its IsSame impl.
+///
+/// This can be derived for structs with `is-same-derive`, in which case
+/// each field is written in the order they're declared.
+pub trait SameFingerprint {
+    /// Feeds the parts of the value that IsSame compares into `state`.
+    fn write_fingerprint<H>(&self, state: &mut H)
+    where
+        H: Hasher;
+}
+
+/// Returns the fingerprint of a value.
+pub fn fingerprint<T>(value: &T) -> u64
+where
+    T: SameFingerprint + ?Sized,
+{
+    let mut hasher = FingerprintHasher::new();
+    value.write_fingerprint(&mut hasher);
+    hasher.finish()
+}
+
+/// The FNV-1a hasher used by `fingerprint`.
+#[derive(Clone, Debug)]
+pub struct FingerprintHasher {
+    state: u64,
+}
+
+impl FingerprintHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    /// Creates a hasher with no input written to it yet.
+    pub fn new() -> FingerprintHasher {
+        FingerprintHasher {
+            state: FingerprintHasher::OFFSET_BASIS,
+        }
+    }
+}
+
+impl Default for FingerprintHasher {
+    fn default() -> FingerprintHasher {
+        FingerprintHasher::new()
+    }
+}
+
+impl Hasher for FingerprintHasher {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state ^= u64::from(byte);
+            self.state = self.state.wrapping_mul(FingerprintHasher::PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, value: u128) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, value: usize) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_i16(&mut self, value: i16) {
+        self.write_u16(value as u16);
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write_u32(value as u32);
+    }
+
+    fn write_i64(&mut self, value: i64) {
+        self.write_u64(value as u64);
+    }
+
+    fn write_i128(&mut self, value: i128) {
+        self.write_u128(value as u128);
+    }
+
+    fn write_isize(&mut self, value: isize) {
+        self.write_u64(value as u64);
+    }
+}
+
+// Only the `Rc` and `Arc` impls below differ from `SameHash`.
+same_hash_impls!(SameFingerprint, write_fingerprint);
+
+#[cfg(feature = "alloc")]
+impl<T> SameFingerprint for Rc<T>
+where
+    T: SameFingerprint + ?Sized,
+{
+    fn write_fingerprint<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).write_fingerprint(state);
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> SameFingerprint for Arc<T>
+where
+    T: SameFingerprint + ?Sized,
+{
+    fn write_fingerprint<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (**self).write_fingerprint(state);
+    }
+}
@@ -0,0 +1,288 @@
+//! Finding where two values first differ.
+//!
+//! When a large structure isn't the same as another, `IsSame` doesn't
+//! say where the mismatch is. `FirstDifference` returns a path to it
+//! instead, such as `settings.panes[3].title`.
+
+use crate::IsSame;
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+/// Finds the first place where two values differ.
+///
+/// This can be derived for structs with `is-same-derive`, in which case
+/// fields are checked in the order they're declared.
+pub trait FirstDifference: IsSame {
+    /// Returns the path to the first difference between `self` and
+    /// `other`, or `None` if they're the same.
+    fn first_difference(&self, other: &Self) -> Option<DiffPath>;
+}
+
+/// One step in a `DiffPath`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A struct field, or the index of a tuple struct field.
+    Field(&'static str),
+    /// An index into a sequence.
+    Index(usize),
+    /// A map key, formatted with `Debug`.
+    Key(String),
+}
+
+/// The path from the root of a value to a difference inside it.
+///
+/// Displays like a Rust expression, for example `panes[3].title` or
+/// `entries["foo"]`. An empty path means the values themselves differ,
+/// and displays as `(root)`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DiffPath {
+    // Stored innermost first, since paths are built up from the
+    // difference outwards.
+    reversed: Vec<PathSegment>,
+}
+
+impl DiffPath {
+    /// Creates an empty path, pointing at the value itself.
+    pub fn new() -> DiffPath {
+        DiffPath::default()
+    }
+
+    /// Adds a segment to the start of the path.
+    pub fn prepend(mut self, segment: PathSegment) -> DiffPath {
+        self.reversed.push(segment);
+        self
+    }
+
+    /// Adds a field to the start of the path.
+    pub fn prepend_field(self, name: &'static str) -> DiffPath {
+        self.prepend(PathSegment::Field(name))
+    }
+
+    /// Adds a sequence index to the start of the path.
+    pub fn prepend_index(self, index: usize) -> DiffPath {
+        self.prepend(PathSegment::Index(index))
+    }
+
+    /// Adds a map key to the start of the path.
+    pub fn prepend_key<Key>(self, key: &Key) -> DiffPath
+    where
+        Key: fmt::Debug + ?Sized,
+    {
+        self.prepend(PathSegment::Key(format!("{:?}", key)))
+    }
+
+    /// Returns true if the path points at the value itself.
+    pub fn is_root(&self) -> bool {
+        self.reversed.is_empty()
+    }
+
+    /// Iterates over the segments, from the root inwards.
+    pub fn segments(&self) -> impl Iterator<Item = &PathSegment> {
+        self.reversed.iter().rev()
+    }
+}
+
+impl fmt::Display for DiffPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_root() {
+            return f.write_str("(root)");
+        }
+        for (index, segment) in self.segments().enumerate() {
+            match segment {
+                PathSegment::Field(name) if index == 0 => f.write_str(name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+                PathSegment::Key(key) => write!(f, "[{}]", key)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+macro_rules! value_first_difference {
+    ($($name:ty),+) => {$(
+        impl FirstDifference for $name {
+            fn first_difference(&self, other: &Self) -> Option<DiffPath> {
+                if self.is_same(other) {
+                    None
+                } else {
+                    Some(DiffPath::new())
+                }
+            }
+        }
+    )+};
+}
+
+value_first_difference!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+value_first_difference!(f32, f64, bool, char, (), str, String);
+
+/// Sequences of different lengths differ at the first index that only
+/// exists on one side, unless an earlier element differs.
+impl<T> FirstDifference for [T]
+where
+    T: FirstDifference,
+{
+    fn first_difference(&self, other: &Self) -> Option<DiffPath> {
+        for (index, (left, right)) in self.iter().zip(other.iter()).enumerate() {
+            if let Some(path) = left.first_difference(right) {
+                return Some(path.prepend_index(index));
+            }
+        }
+        if self.len() != other.len() {
+            Some(DiffPath::new().prepend_index(self.len().min(other.len())))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, const N: usize> FirstDifference for [T; N]
+where
+    T: FirstDifference,
+{
+    fn first_difference(&self, other: &Self) -> Option<DiffPath> {
+        self[..].first_difference(&other[..])
+    }
+}
+
+impl<T> FirstDifference for Vec<T>
+where
+    T: FirstDifference,
+{
+    fn first_difference(&self, other: &Self) -> Option<DiffPath> {
+        self[..].first_difference(&other[..])
+    }
+}
+
+impl<T> FirstDifference for Option<T>
+where
+    T: FirstDifference,
+{
+    fn first_difference(&self, other: &Self) -> Option<DiffPath> {
+        match (self, other) {
+            (Some(left), Some(right)) => left.first_difference(right),
+            (None, None) => None,
+            (_, _) => Some(DiffPath::new()),
+        }
+    }
+}
+
+/// Keys are checked in order, and the path points at the first key
+/// that is missing from one side, isn't the same as the key on the other
+/// side, or has a different value.
+impl<Key, Value> FirstDifference for BTreeMap<Key, Value>
+where
+    Key: IsSame + Ord + fmt::Debug,
+    Value: FirstDifference,
+{
+    fn first_difference(&self, other: &Self) -> Option<DiffPath> {
+        let mut left = self.iter().peekable();
+        let mut right = other.iter().peekable();
+        loop {
+            match (left.peek(), right.peek()) {
+                (None, None) => return None,
+                (Some((key, _)), None) | (None, Some((key, _))) => {
+                    return Some(DiffPath::new().prepend_key(*key))
+                }
+                (Some((left_key, left_val)), Some((right_key, right_val))) => {
+                    if left_key < right_key || left_key.is_not_same(right_key) {
+                        return Some(DiffPath::new().prepend_key(*left_key));
+                    } else if right_key < left_key {
+                        return Some(DiffPath::new().prepend_key(*right_key));
+                    } else if let Some(path) = left_val.first_difference(right_val) {
+                        return Some(path.prepend_key(*left_key));
+                    }
+                }
+            }
+            left.next();
+            right.next();
+        }
+    }
+}
+
+/// Hash maps have no order, so which difference is found first isn't
+/// specified when there's more than one.
+#[cfg(feature = "std")]
+impl<Key, Value, State> FirstDifference for HashMap<Key, Value, State>
+where
+    Key: IsSame + Eq + Hash + fmt::Debug,
+    Value: FirstDifference,
+    State: BuildHasher,
+{
+    fn first_difference(&self, other: &Self) -> Option<DiffPath> {
+        for (key, left) in self {
+            match other.get_key_value(key) {
+                Some((right_key, right)) if key.is_same(right_key) => {
+                    if let Some(path) = left.first_difference(right) {
+                        return Some(path.prepend_key(key));
+                    }
+                }
+                _ => return Some(DiffPath::new().prepend_key(key)),
+            }
+        }
+        other
+            .keys()
+            .find(|key| match self.get_key_value(*key) {
+                Some((left_key, _)) => left_key.is_not_same(*key),
+                None => true,
+            })
+            .map(|key| DiffPath::new().prepend_key(key))
+    }
+}
+
+macro_rules! tuple_first_difference {
+    ($($name:ident $index:tt),+) => {
+        /// Tuple elements show up in the path like fields, as `.0`.
+        impl<$($name),+> FirstDifference for ($($name,)+)
+        where
+            $($name: FirstDifference),+
+        {
+            fn first_difference(&self, other: &Self) -> Option<DiffPath> {
+                $(
+                    if let Some(path) = self.$index.first_difference(&other.$index) {
+                        return Some(path.prepend_field(stringify!($index)));
+                    }
+                )+
+                None
+            }
+        }
+    };
+}
+
+/// Invokes `tuple_first_difference!` for every prefix of the given list, so that
+/// each tuple length up to the full list gets an impl.
+macro_rules! tuple_first_differences {
+    (@prefix [$($done:tt)*]) => {};
+    (@prefix [$($done:tt)*] $name:ident $index:tt; $($rest:tt)*) => {
+        tuple_first_difference!($($done)* $name $index);
+        tuple_first_differences! { @prefix [$($done)* $name $index,] $($rest)* }
+    };
+    ($($list:tt)+) => {
+        tuple_first_differences! { @prefix [] $($list)+ }
+    };
+}
+
+tuple_first_differences! {
+    A 0;
+    B 1;
+    C 2;
+    D 3;
+    E 4;
+    F 5;
+    G 6;
+    H 7;
+    I 8;
+    J 9;
+    K 10;
+    L 11;
+    M 12;
+    N 13;
+    O 14;
+    P 15;
+}
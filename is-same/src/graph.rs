@@ -0,0 +1,155 @@
+//! Deep comparison of reference counted graphs that may contain cycles.
+//!
+//! `Rc<T>` is normally compared by pointer, and `Deep<Rc<T>>` compares
+//! the contents recursively, which never finishes on a cyclic graph.
+//! [`GraphComparison`] compares through the links instead, keeping track
+//! of which pairs of nodes it's already comparing. When it reaches a pair
+//! a second time it assumes they're the same, so two graphs are the same
+//! when unrolling both of them from the roots would give the same trees.
+//!
+//! ```
+//! use is_same::graph::{graph_is_same, GraphComparison, GraphNode};
+//! use is_same::IsSame;
+//! use std::cell::RefCell;
+//! use std::rc::Rc;
+//!
+//! struct Node {
+//!     value: f32,
+//!     next: Option<Rc<RefCell<Node>>>,
+//! }
+//!
+//! impl GraphNode for Node {
+//!     fn is_same_node(&self, other: &Node, graph: &mut GraphComparison) -> bool {
+//!         self.value.is_same(&other.value) && graph.compare_option(&self.next, &other.next)
+//!     }
+//! }
+//!
+//! let ring = |value| {
+//!     let node = Rc::new(RefCell::new(Node { value, next: None }));
+//!     node.borrow_mut().next = Some(node.clone());
+//!     node
+//! };
+//! assert!(graph_is_same(&ring(f32::NAN), &ring(f32::NAN)));
+//! assert!(!graph_is_same(&ring(1.0), &ring(2.0)));
+//! ```
+
+use alloc::collections::BTreeSet;
+use alloc::rc::{Rc, Weak};
+use core::cell::RefCell;
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+/// A node in a graph that `GraphComparison` can walk.
+///
+/// Implementations compare the node's own data with IsSame, and compare
+/// links to other nodes through `graph`, so that cycles are detected.
+pub trait GraphNode {
+    /// Returns true if the two nodes are the same.
+    fn is_same_node(&self, other: &Self, graph: &mut GraphComparison) -> bool;
+}
+
+/// Borrows both cells for the comparison.
+///
+/// # Panics
+///
+/// Panics if either cell is mutably borrowed.
+impl<T> GraphNode for RefCell<T>
+where
+    T: GraphNode + ?Sized,
+{
+    fn is_same_node(&self, other: &Self, graph: &mut GraphComparison) -> bool {
+        self.borrow().is_same_node(&other.borrow(), graph)
+    }
+}
+
+/// Keeps track of the pairs of nodes that are being compared.
+#[derive(Clone, Debug, Default)]
+pub struct GraphComparison {
+    visited: BTreeSet<(usize, usize)>,
+}
+
+/// Compares two graphs starting from the given roots.
+pub fn graph_is_same<T>(left: &Rc<T>, right: &Rc<T>) -> bool
+where
+    T: GraphNode + ?Sized,
+{
+    GraphComparison::new().compare_rc(left, right)
+}
+
+impl GraphComparison {
+    /// Starts a comparison that hasn't visited any nodes.
+    pub fn new() -> GraphComparison {
+        GraphComparison::default()
+    }
+
+    /// Compares two nodes by address. Nodes at the same address are the
+    /// same, and a pair that's already being compared is assumed to be
+    /// the same. Otherwise the contents are compared.
+    pub fn compare<T>(&mut self, left: &T, right: &T) -> bool
+    where
+        T: GraphNode + ?Sized,
+    {
+        let left_addr = (left as *const T).cast::<u8>() as usize;
+        let right_addr = (right as *const T).cast::<u8>() as usize;
+        if left_addr == right_addr || !self.visited.insert((left_addr, right_addr)) {
+            return true;
+        }
+        left.is_same_node(right, self)
+    }
+
+    /// Compares the nodes that two `Rc`s point to.
+    pub fn compare_rc<T>(&mut self, left: &Rc<T>, right: &Rc<T>) -> bool
+    where
+        T: GraphNode + ?Sized,
+    {
+        self.compare(&**left, &**right)
+    }
+
+    /// Compares the nodes that two `Arc`s point to.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn compare_arc<T>(&mut self, left: &Arc<T>, right: &Arc<T>) -> bool
+    where
+        T: GraphNode + ?Sized,
+    {
+        self.compare(&**left, &**right)
+    }
+
+    /// Compares the nodes behind two weak links, such as parent pointers.
+    /// Two dangling links are the same, while a dangling link isn't the
+    /// same as a live one.
+    pub fn compare_weak<T>(&mut self, left: &Weak<T>, right: &Weak<T>) -> bool
+    where
+        T: GraphNode,
+    {
+        match (left.upgrade(), right.upgrade()) {
+            (Some(left), Some(right)) => self.compare_rc(&left, &right),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Compares two optional links.
+    pub fn compare_option<T>(&mut self, left: &Option<Rc<T>>, right: &Option<Rc<T>>) -> bool
+    where
+        T: GraphNode + ?Sized,
+    {
+        match (left, right) {
+            (Some(left), Some(right)) => self.compare_rc(left, right),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Compares two lists of links in order.
+    pub fn compare_slice<T>(&mut self, left: &[Rc<T>], right: &[Rc<T>]) -> bool
+    where
+        T: GraphNode + ?Sized,
+    {
+        left.len() == right.len()
+            && left
+                .iter()
+                .zip(right.iter())
+                .all(|(left, right)| self.compare_rc(left, right))
+    }
+}
@@ -0,0 +1,269 @@
+//! The impls shared by `SameFingerprint` and `SameHash`.
+//!
+//! Both traits hash values the same way except for `Rc` and `Arc`, which
+//! a fingerprint hashes by their contents and `SameHash` by their
+//! addresses. Everything else is generated by `same_hash_impls!`, which
+//! takes the trait and its method, so each module only has to write its
+//! own pointer impls.
+
+macro_rules! same_hash_impls {
+    ($trait:ident, $method:ident) => {
+        // The Hash impls for these already follow the IsSame rules.
+        same_hash_impls!(@hash $trait, $method; u8, u16, u32, u64, u128, usize);
+        same_hash_impls!(@hash $trait, $method; i8, i16, i32, i64, i128, isize);
+        same_hash_impls!(@hash $trait, $method; bool, char, (), str);
+
+        #[cfg(feature = "alloc")]
+        same_hash_impls!(@hash $trait, $method; alloc::string::String);
+
+        impl $trait for f32 {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                state.write_u32(self.to_bits());
+            }
+        }
+
+        impl $trait for f64 {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                state.write_u64(self.to_bits());
+            }
+        }
+
+        impl<T> $trait for &T
+        where
+            T: $trait + ?Sized,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                (**self).$method(state);
+            }
+        }
+
+        impl<T> $trait for Option<T>
+        where
+            T: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                match self {
+                    Some(value) => {
+                        state.write_u8(1);
+                        value.$method(state);
+                    }
+                    None => state.write_u8(0),
+                }
+            }
+        }
+
+        impl<T, E> $trait for Result<T, E>
+        where
+            T: $trait,
+            E: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                match self {
+                    Ok(value) => {
+                        state.write_u8(0);
+                        value.$method(state);
+                    }
+                    Err(error) => {
+                        state.write_u8(1);
+                        error.$method(state);
+                    }
+                }
+            }
+        }
+
+        // Sequences write their length first, so that `[[1], [2, 3]]` and
+        // `[[1, 2], [3]]` don't write the same input.
+        fn hash_sequence<'a, T, H, I>(len: usize, items: I, state: &mut H)
+        where
+            T: $trait + 'a,
+            H: core::hash::Hasher,
+            I: Iterator<Item = &'a T>,
+        {
+            state.write_usize(len);
+            for item in items {
+                item.$method(state);
+            }
+        }
+
+        impl<T> $trait for [T]
+        where
+            T: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                hash_sequence(self.len(), self.iter(), state);
+            }
+        }
+
+        impl<T, const N: usize> $trait for [T; N]
+        where
+            T: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                self[..].$method(state);
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T> $trait for alloc::vec::Vec<T>
+        where
+            T: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                self[..].$method(state);
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T> $trait for alloc::collections::VecDeque<T>
+        where
+            T: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                hash_sequence(self.len(), self.iter(), state);
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<T> $trait for alloc::collections::BTreeSet<T>
+        where
+            T: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                hash_sequence(self.len(), self.iter(), state);
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<Key, Value> $trait for alloc::collections::BTreeMap<Key, Value>
+        where
+            Key: $trait,
+            Value: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                state.write_usize(self.len());
+                for (key, value) in self {
+                    key.$method(state);
+                    value.$method(state);
+                }
+            }
+        }
+
+        // Hash maps and sets iterate in an arbitrary order, so each entry
+        // is hashed on its own and the results are combined with an
+        // addition, which doesn't depend on the order.
+        #[cfg(feature = "std")]
+        fn hash_unordered<T, H, I>(len: usize, items: I, state: &mut H)
+        where
+            T: $trait,
+            H: core::hash::Hasher,
+            I: Iterator<Item = T>,
+        {
+            let sum = items.fold(0u64, |sum, item| {
+                let mut hasher = crate::fingerprint::FingerprintHasher::new();
+                item.$method(&mut hasher);
+                sum.wrapping_add(core::hash::Hasher::finish(&hasher))
+            });
+            state.write_usize(len);
+            state.write_u64(sum);
+        }
+
+        #[cfg(feature = "std")]
+        impl<Key, Value, State> $trait for std::collections::HashMap<Key, Value, State>
+        where
+            Key: $trait,
+            Value: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                hash_unordered(self.len(), self.iter(), state);
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<Key, State> $trait for std::collections::HashSet<Key, State>
+        where
+            Key: $trait,
+        {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                hash_unordered(self.len(), self.iter(), state);
+            }
+        }
+
+        same_hash_impls!(@tuples $trait, $method; []
+            A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7,
+            I 8, J 9, K 10, L 11, M 12, N 13, O 14, P 15,
+        );
+    };
+    (@hash $trait:ident, $method:ident; $($ty:ty),+) => {$(
+        impl $trait for $ty {
+            fn $method<H>(&self, state: &mut H)
+            where
+                H: core::hash::Hasher,
+            {
+                core::hash::Hash::hash(self, state);
+            }
+        }
+    )+};
+    // Implements the trait for every prefix of the list, so that each
+    // tuple length up to 16 gets an impl.
+    (@tuples $trait:ident, $method:ident; [$($done:tt)*]) => {};
+    (@tuples $trait:ident, $method:ident; [$($done_name:ident $done_index:tt,)*]
+        $name:ident $index:tt, $($rest:tt)*
+    ) => {
+        same_hash_impls!(@tuple $trait, $method; $($done_name $done_index,)* $name $index);
+        same_hash_impls!(@tuples $trait, $method; [$($done_name $done_index,)* $name $index,]
+            $($rest)*
+        );
+    };
+    (@tuple $trait:ident, $method:ident; $($name:ident $index:tt),+) => {
+        impl<$($name),+> $trait for ($($name,)+)
+        where
+            $($name: $trait),+
+        {
+            fn $method<Hs>(&self, state: &mut Hs)
+            where
+                Hs: core::hash::Hasher,
+            {
+                $(self.$index.$method(state);)+
+            }
+        }
+    };
+}
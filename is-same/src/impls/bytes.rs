@@ -0,0 +1,26 @@
+use crate::IsSame;
+use bytes::{Bytes, BytesMut};
+
+/// Handles that view the same region of a shared buffer are the same
+/// without comparing their contents.
+fn bytes_is_same(left: &[u8], right: &[u8]) -> bool {
+    (left.len() == right.len() && left.as_ptr() == right.as_ptr()) || left == right
+}
+
+impl<Rhs> IsSame<Rhs> for Bytes
+where
+    Rhs: AsRef<[u8]> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        bytes_is_same(self, other.as_ref())
+    }
+}
+
+impl<Rhs> IsSame<Rhs> for BytesMut
+where
+    Rhs: AsRef<[u8]> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        bytes_is_same(self, other.as_ref())
+    }
+}
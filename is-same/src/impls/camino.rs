@@ -0,0 +1,20 @@
+use crate::IsSame;
+use camino::{Utf8Path, Utf8PathBuf};
+
+impl<Rhs> IsSame<Rhs> for Utf8PathBuf
+where
+    Rhs: AsRef<Utf8Path> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        self == other.as_ref()
+    }
+}
+
+impl<Rhs> IsSame<Rhs> for Utf8Path
+where
+    Rhs: AsRef<Utf8Path> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        self == other.as_ref()
+    }
+}
@@ -0,0 +1,27 @@
+use crate::IsSame;
+use chrono::{
+    DateTime, FixedOffset, Month, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta, TimeZone,
+    Utc, Weekday,
+};
+
+/// Two datetimes are the same if they refer to the same instant and
+/// have the same UTC offset. This is stricter than `PartialEq`, which
+/// treats the same instant in different offsets as equal, since the
+/// offset affects how the value is displayed.
+impl<Tz> IsSame for DateTime<Tz>
+where
+    Tz: TimeZone,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.naive_utc() == other.naive_utc() && self.offset().fix() == other.offset().fix()
+    }
+}
+
+simple_impl!(NaiveDate);
+simple_impl!(NaiveTime);
+simple_impl!(NaiveDateTime);
+simple_impl!(TimeDelta);
+simple_impl!(FixedOffset);
+simple_impl!(Utc);
+simple_impl!(Weekday);
+simple_impl!(Month);
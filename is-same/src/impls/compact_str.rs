@@ -0,0 +1,11 @@
+use crate::IsSame;
+use compact_str::CompactString;
+
+impl<Rhs> IsSame<Rhs> for CompactString
+where
+    Rhs: AsRef<str> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        self.as_str() == other.as_ref()
+    }
+}
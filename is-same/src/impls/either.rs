@@ -0,0 +1,16 @@
+use crate::IsSame;
+use either::Either;
+
+impl<L, R, M, S> IsSame<Either<M, S>> for Either<L, R>
+where
+    L: IsSame<M>,
+    R: IsSame<S>,
+{
+    fn is_same(&self, other: &Either<M, S>) -> bool {
+        match (self, other) {
+            (Either::Left(left), Either::Left(right)) => left.is_same(right),
+            (Either::Right(left), Either::Right(right)) => left.is_same(right),
+            (_, _) => false,
+        }
+    }
+}
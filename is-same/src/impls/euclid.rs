@@ -0,0 +1,50 @@
+use crate::IsSame;
+use euclid::{
+    Angle, Box2D, Box3D, HomogeneousVector, Length, Point2D, Point3D, Rect, RigidTransform3D,
+    Rotation2D, Rotation3D, Scale, SideOffsets2D, Size2D, Size3D, Transform2D, Transform3D,
+    Translation2D, Translation3D, Vector2D, Vector3D,
+};
+
+/// Compares each of the listed fields with IsSame, so float components
+/// are compared by their bit patterns. The unit type parameters are only
+/// markers and don't need any bounds.
+macro_rules! euclid_impl {
+    ($($name:ident<T $(, $param:ident)*> { $($field:tt),+ }),+ $(,)?) => {
+        $(
+            impl<T $(, $param)*> IsSame for $name<T $(, $param)*>
+            where
+                T: IsSame,
+            {
+                fn is_same(&self, other: &Self) -> bool {
+                    $(self.$field.is_same(&other.$field))&&+
+                }
+            }
+        )+
+    };
+}
+
+euclid_impl! {
+    Point2D<T, U> { x, y },
+    Point3D<T, U> { x, y, z },
+    Vector2D<T, U> { x, y },
+    Vector3D<T, U> { x, y, z },
+    HomogeneousVector<T, U> { x, y, z, w },
+    Size2D<T, U> { width, height },
+    Size3D<T, U> { width, height, depth },
+    Rect<T, U> { origin, size },
+    Box2D<T, U> { min, max },
+    Box3D<T, U> { min, max },
+    SideOffsets2D<T, U> { top, right, bottom, left },
+    Length<T, U> { 0 },
+    Scale<T, Src, Dst> { 0 },
+    Angle<T> { radians },
+    Translation2D<T, Src, Dst> { x, y },
+    Translation3D<T, Src, Dst> { x, y, z },
+    Rotation2D<T, Src, Dst> { angle },
+    Rotation3D<T, Src, Dst> { i, j, k, r },
+    RigidTransform3D<T, Src, Dst> { rotation, translation },
+    Transform2D<T, Src, Dst> { m11, m12, m21, m22, m31, m32 },
+    Transform3D<T, Src, Dst> {
+        m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44
+    },
+}
@@ -0,0 +1,19 @@
+use crate::IsSame;
+use generational_arena::{Arena, Index};
+
+simple_impl!(Index);
+
+impl<T> IsSame for Arena<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|((left_index, left), (right_index, right))| {
+                    left_index == right_index && left.is_same(right)
+                })
+    }
+}
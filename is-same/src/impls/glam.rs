@@ -0,0 +1,34 @@
+use crate::IsSame;
+use glam::{
+    Affine2, Affine3A, BVec2, BVec3, BVec4, DAffine2, DAffine3, DMat2, DMat3, DMat4, DQuat, DVec2,
+    DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, UVec2, UVec3, UVec4, Vec2,
+    Vec3, Vec3A, Vec4,
+};
+
+/// Compares the float components bitwise through the array the type
+/// converts to.
+macro_rules! glam_impl {
+    ($to_array:ident: $($name:ident),*) => {
+        $(
+            impl IsSame for $name {
+                fn is_same(&self, other: &Self) -> bool {
+                    self.$to_array().is_same(&other.$to_array())
+                }
+            }
+        )*
+    };
+}
+
+glam_impl!(to_array: Vec2, Vec3, Vec3A, Vec4, DVec2, DVec3, DVec4, Quat, DQuat);
+glam_impl!(to_cols_array: Mat2, Mat3, Mat3A, Mat4, DMat2, DMat3, DMat4);
+glam_impl!(to_cols_array: Affine2, Affine3A, DAffine2, DAffine3);
+
+simple_impl!(IVec2);
+simple_impl!(IVec3);
+simple_impl!(IVec4);
+simple_impl!(UVec2);
+simple_impl!(UVec3);
+simple_impl!(UVec4);
+simple_impl!(BVec2);
+simple_impl!(BVec3);
+simple_impl!(BVec4);
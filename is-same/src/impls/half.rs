@@ -0,0 +1,14 @@
+use crate::IsSame;
+use half::{bf16, f16};
+
+impl IsSame for f16 {
+    fn is_same(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
+
+impl IsSame for bf16 {
+    fn is_same(&self, other: &Self) -> bool {
+        self.to_bits() == other.to_bits()
+    }
+}
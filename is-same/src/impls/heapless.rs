@@ -0,0 +1,80 @@
+use crate::IsSame;
+use heapless::{Deque, IndexMap, IndexSet, String, Vec};
+
+impl<T, U, const N: usize, const M: usize> IsSame<Vec<U, M>> for Vec<T, N>
+where
+    T: IsSame<U>,
+{
+    fn is_same(&self, other: &Vec<U, M>) -> bool {
+        self.as_slice().is_same(other.as_slice())
+    }
+}
+
+impl<T, U, const N: usize> IsSame<[U]> for Vec<T, N>
+where
+    T: IsSame<U>,
+{
+    fn is_same(&self, other: &[U]) -> bool {
+        self.as_slice().is_same(other)
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> IsSame<[U; M]> for Vec<T, N>
+where
+    T: IsSame<U>,
+{
+    fn is_same(&self, other: &[U; M]) -> bool {
+        self.as_slice().is_same(other)
+    }
+}
+
+impl<Rhs, const N: usize> IsSame<Rhs> for String<N>
+where
+    Rhs: AsRef<str> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        self.as_str() == other.as_ref()
+    }
+}
+
+impl<T, const N: usize, const M: usize> IsSame<Deque<T, M>> for Deque<T, N>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Deque<T, M>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(left, right)| left.is_same(right))
+    }
+}
+
+impl<Key, Value, State, OtherState, const N: usize, const M: usize>
+    IsSame<IndexMap<Key, Value, OtherState, M>> for IndexMap<Key, Value, State, N>
+where
+    Key: IsSame,
+    Value: IsSame,
+{
+    fn is_same(&self, other: &IndexMap<Key, Value, OtherState, M>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(left, right)| left.is_same(&right))
+    }
+}
+
+impl<Key, State, OtherState, const N: usize, const M: usize> IsSame<IndexSet<Key, OtherState, M>>
+    for IndexSet<Key, State, N>
+where
+    Key: IsSame,
+{
+    fn is_same(&self, other: &IndexSet<Key, OtherState, M>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(left, right)| left.is_same(right))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::iter::iter_is_same_by;
+use crate::IsSame;
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use http::{Method, StatusCode, Uri, Version};
+
+simple_impl!(HeaderName);
+simple_impl!(HeaderValue);
+simple_impl!(Method);
+simple_impl!(StatusCode);
+simple_impl!(Uri);
+simple_impl!(Version);
+
+/// Compared as a multimap: the order the headers were inserted in
+/// doesn't matter, but the values for each header name are compared in
+/// order, since that order is meaningful in HTTP.
+impl<T> IsSame for HeaderMap<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        // Checking both lengths makes sure `other` has no extra names or
+        // values.
+        if self.len() != other.len() || self.keys_len() != other.keys_len() {
+            return false;
+        }
+        self.keys().all(|key| {
+            iter_is_same_by(self.get_all(key), other.get_all(key), |left, right| {
+                left.is_same(right)
+            })
+        })
+    }
+}
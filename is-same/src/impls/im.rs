@@ -0,0 +1,143 @@
+//! The persistent collections from `im` and `im-rc` share structure
+//! between clones, so each impl first checks whether the two sides
+//! share their root with `ptr_eq` before comparing any elements.
+
+macro_rules! im_impl {
+    ($krate:ident) => {
+        mod $krate {
+            use crate::IsSame;
+            use ::$krate::{HashMap, HashSet, OrdMap, OrdSet, Vector};
+            use core::hash::{BuildHasher, Hash};
+
+            impl<A> IsSame for Vector<A>
+            where
+                A: Clone + IsSame,
+            {
+                fn is_same(&self, other: &Self) -> bool {
+                    if self.ptr_eq(other) {
+                        return true;
+                    }
+                    if self.len() != other.len() {
+                        return false;
+                    }
+                    // Walk the leaf chunks of both vectors in step. While
+                    // the chunk boundaries line up, each step compares a
+                    // whole chunk, so chunks still shared between the two
+                    // are skipped by the slice impl's pointer check. Once
+                    // they drift apart, for example after a `push_front`
+                    // on one side, the rest is compared element by element.
+                    let mut left_leaves = self.leaves();
+                    let mut right_leaves = other.leaves();
+                    let mut left: &[A] = &[];
+                    let mut right: &[A] = &[];
+                    loop {
+                        if left.is_empty() {
+                            match left_leaves.next() {
+                                Some(chunk) => left = chunk,
+                                None => return true,
+                            }
+                        }
+                        if right.is_empty() {
+                            match right_leaves.next() {
+                                Some(chunk) => right = chunk,
+                                None => return true,
+                            }
+                        }
+                        let len = left.len().min(right.len());
+                        if left[..len].is_not_same(&right[..len]) {
+                            return false;
+                        }
+                        left = &left[len..];
+                        right = &right[len..];
+                    }
+                }
+            }
+
+            impl<Key, Value, State> IsSame for HashMap<Key, Value, State>
+            where
+                Key: IsSame + Eq + Hash,
+                Value: IsSame,
+                State: BuildHasher,
+            {
+                fn is_same(&self, other: &Self) -> bool {
+                    if self.ptr_eq(other) {
+                        return true;
+                    }
+                    // Keys are looked up by Eq, then compared with IsSame,
+                    // like the std `HashMap` impl.
+                    self.len() == other.len()
+                        && self
+                            .iter()
+                            .all(|(key, left_val)| match other.get_key_value(key) {
+                                Some((right_key, right_val)) => {
+                                    key.is_same(right_key) && left_val.is_same(right_val)
+                                }
+                                None => false,
+                            })
+                }
+            }
+
+            impl<Key, State> IsSame for HashSet<Key, State>
+            where
+                Key: IsSame + Clone + Eq + Hash,
+                State: BuildHasher,
+            {
+                fn is_same(&self, other: &Self) -> bool {
+                    if self.ptr_eq(other) {
+                        return true;
+                    }
+                    if self.len() != other.len() {
+                        return false;
+                    }
+                    // `HashSet` has no way to get at the stored element for
+                    // a key, but removing it from a clone returns it, and
+                    // cloning only copies the root.
+                    let mut rest = other.clone();
+                    self.iter().all(|key| match rest.remove(key) {
+                        Some(found) => key.is_same(&found),
+                        None => false,
+                    })
+                }
+            }
+
+            impl<Key, Value> IsSame for OrdMap<Key, Value>
+            where
+                Key: IsSame + Ord,
+                Value: IsSame,
+            {
+                fn is_same(&self, other: &Self) -> bool {
+                    if self.ptr_eq(other) {
+                        return true;
+                    }
+                    self.len() == other.len()
+                        && self.iter().zip(other.iter()).all(
+                            |((left_key, left_val), (right_key, right_val))| {
+                                left_key.is_same(right_key) && left_val.is_same(right_val)
+                            },
+                        )
+                }
+            }
+
+            impl<Key> IsSame for OrdSet<Key>
+            where
+                Key: IsSame + Ord,
+            {
+                fn is_same(&self, other: &Self) -> bool {
+                    if self.ptr_eq(other) {
+                        return true;
+                    }
+                    self.len() == other.len()
+                        && self
+                            .iter()
+                            .zip(other.iter())
+                            .all(|(left, right)| left.is_same(right))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "im")]
+im_impl!(im);
+#[cfg(feature = "im-rc")]
+im_impl!(im_rc);
@@ -0,0 +1,35 @@
+//! Unlike the std HashMap and HashSet impls, these compare entries in
+//! order: two maps holding the same entries in a different insertion
+//! order are not the same. Keys are compared using IsSame, since the
+//! entries are matched up by position rather than by lookup.
+
+use crate::IsSame;
+use indexmap::{IndexMap, IndexSet};
+
+impl<Key, Value, State, OtherState> IsSame<IndexMap<Key, Value, OtherState>>
+    for IndexMap<Key, Value, State>
+where
+    Key: IsSame,
+    Value: IsSame,
+{
+    fn is_same(&self, other: &IndexMap<Key, Value, OtherState>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(left, right)| left.is_same(&right))
+    }
+}
+
+impl<Key, State, OtherState> IsSame<IndexSet<Key, OtherState>> for IndexSet<Key, State>
+where
+    Key: IsSame,
+{
+    fn is_same(&self, other: &IndexSet<Key, OtherState>) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(left, right)| left.is_same(right))
+    }
+}
@@ -0,0 +1,31 @@
+use crate::IsSame;
+use js_sys::{
+    Array, ArrayBuffer, BigInt, Boolean, Date, Error, JsString, Map, Number, Object, RegExp, Set,
+    Symbol,
+};
+
+/// Compares the handles with `Object.is`, the same way as `JsValue`.
+macro_rules! js_impl {
+    ($($name:ident $(<$($param:ident),*>)?),*) => {
+        $(
+            impl$(<$($param),*>)? IsSame for $name$(<$($param),*>)? {
+                fn is_same(&self, other: &Self) -> bool {
+                    Object::is(self.as_ref(), other.as_ref())
+                }
+            }
+        )*
+    };
+}
+
+js_impl!(Object<T>, Array<T>, Map<K, V>, Set<T>);
+js_impl!(
+    ArrayBuffer,
+    BigInt,
+    Boolean,
+    Date,
+    Error,
+    JsString,
+    Number,
+    RegExp,
+    Symbol
+);
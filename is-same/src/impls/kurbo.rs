@@ -0,0 +1,82 @@
+use crate::IsSame;
+use kurbo::{
+    Affine, Arc, BezPath, Circle, CubicBez, Insets, Line, PathEl, PathSeg, Point, QuadBez, Rect,
+    RoundedRect, RoundedRectRadii, Size, TranslateScale, Vec2,
+};
+
+/// Compares each of the listed fields with IsSame, so coordinates are
+/// compared by their bit patterns.
+macro_rules! kurbo_impl {
+    ($($name:ident { $($field:ident),+ }),+ $(,)?) => {
+        $(
+            impl IsSame for $name {
+                fn is_same(&self, other: &Self) -> bool {
+                    $(self.$field.is_same(&other.$field))&&+
+                }
+            }
+        )+
+    };
+}
+
+kurbo_impl! {
+    Point { x, y },
+    Vec2 { x, y },
+    Size { width, height },
+    Rect { x0, y0, x1, y1 },
+    Insets { x0, y0, x1, y1 },
+    RoundedRectRadii { top_left, top_right, bottom_right, bottom_left },
+    Line { p0, p1 },
+    QuadBez { p0, p1, p2 },
+    CubicBez { p0, p1, p2, p3 },
+    Circle { center, radius },
+    Arc { center, radii, start_angle, sweep_angle, x_rotation },
+    TranslateScale { translation, scale },
+}
+
+impl IsSame for Affine {
+    fn is_same(&self, other: &Self) -> bool {
+        self.as_coeffs().is_same(&other.as_coeffs())
+    }
+}
+
+impl IsSame for RoundedRect {
+    fn is_same(&self, other: &Self) -> bool {
+        self.rect().is_same(&other.rect()) && self.radii().is_same(&other.radii())
+    }
+}
+
+impl IsSame for PathEl {
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PathEl::MoveTo(left), PathEl::MoveTo(right)) => left.is_same(right),
+            (PathEl::LineTo(left), PathEl::LineTo(right)) => left.is_same(right),
+            (PathEl::QuadTo(left1, left2), PathEl::QuadTo(right1, right2)) => {
+                left1.is_same(right1) && left2.is_same(right2)
+            }
+            (PathEl::CurveTo(left1, left2, left3), PathEl::CurveTo(right1, right2, right3)) => {
+                left1.is_same(right1) && left2.is_same(right2) && left3.is_same(right3)
+            }
+            (PathEl::ClosePath, PathEl::ClosePath) => true,
+            (_, _) => false,
+        }
+    }
+}
+
+impl IsSame for PathSeg {
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (PathSeg::Line(left), PathSeg::Line(right)) => left.is_same(right),
+            (PathSeg::Quad(left), PathSeg::Quad(right)) => left.is_same(right),
+            (PathSeg::Cubic(left), PathSeg::Cubic(right)) => left.is_same(right),
+            (_, _) => false,
+        }
+    }
+}
+
+/// Paths are compared element by element, so the same shape drawn
+/// starting from a different point isn't the same.
+impl IsSame for BezPath {
+    fn is_same(&self, other: &Self) -> bool {
+        self.elements().is_same(other.elements())
+    }
+}
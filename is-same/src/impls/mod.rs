@@ -0,0 +1,96 @@
+//! IsSame impls for types from other crates. Each one is behind a cargo
+//! feature with the same name as the crate, except for `portable_simd`,
+//! which is named after the nightly feature it enables.
+
+#[cfg(feature = "bytes")]
+mod bytes;
+#[cfg(feature = "camino")]
+mod camino;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "compact_str")]
+mod compact_str;
+#[cfg(feature = "either")]
+mod either;
+#[cfg(feature = "euclid")]
+mod euclid;
+#[cfg(feature = "generational-arena")]
+mod generational_arena;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "half")]
+mod half;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(any(feature = "im", feature = "im-rc"))]
+mod im;
+#[cfg(feature = "indexmap")]
+mod indexmap;
+#[cfg(feature = "js-sys")]
+mod js_sys;
+#[cfg(feature = "kurbo")]
+mod kurbo;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "num-rational")]
+mod num_rational;
+#[cfg(feature = "petgraph")]
+mod petgraph;
+#[cfg(feature = "portable_simd")]
+mod portable_simd;
+#[cfg(feature = "regex")]
+mod regex;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "semver")]
+mod semver;
+#[cfg(feature = "serde_json")]
+mod serde_json;
+#[cfg(feature = "serde_yaml")]
+mod serde_yaml;
+#[cfg(feature = "smol_str")]
+mod smol_str;
+#[cfg(feature = "time")]
+mod time;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "url")]
+mod url;
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm_bindgen;
+#[cfg(feature = "wide")]
+mod wide;
+
+/// Compares the entries of two maps regardless of their order, for the
+/// document formats whose maps don't implement IsSame generically.
+///
+/// Each entry on the left is found on the right with `lookup`, which
+/// returns the key and value as they're stored there, and both are
+/// compared with IsSame. Since the maps have the same length, finding
+/// every key on the left also means the right has no extra keys.
+#[cfg(any(feature = "serde_json", feature = "serde_yaml", feature = "toml"))]
+fn unordered_map_is_same<'l, 'r, Key, Value, Lookup>(
+    left: impl Iterator<Item = (&'l Key, &'l Value)>,
+    left_len: usize,
+    right_len: usize,
+    mut lookup: Lookup,
+) -> bool
+where
+    Key: crate::IsSame + 'l + 'r,
+    Value: crate::IsSame + 'l + 'r,
+    Lookup: FnMut(&'l Key) -> Option<(&'r Key, &'r Value)>,
+{
+    left_len == right_len
+        && left.into_iter().all(|(key, left_val)| match lookup(key) {
+            Some((right_key, right_val)) => key.is_same(right_key) && left_val.is_same(right_val),
+            None => false,
+        })
+}
@@ -0,0 +1,94 @@
+use crate::IsSame;
+use nalgebra::allocator::Allocator;
+use nalgebra::{
+    DefaultAllocator, Dim, DimName, Isometry, Matrix, OPoint, Quaternion, RawStorage, Rotation,
+    Scalar, Similarity, Translation, Unit,
+};
+
+/// Matrices with different storage can be compared as long as they have
+/// the same shape. Elements are compared in column-major order.
+impl<T, R, C, S, U, R2, C2, S2> IsSame<Matrix<U, R2, C2, S2>> for Matrix<T, R, C, S>
+where
+    T: IsSame<U>,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+    R2: Dim,
+    C2: Dim,
+    S2: RawStorage<U, R2, C2>,
+{
+    fn is_same(&self, other: &Matrix<U, R2, C2, S2>) -> bool {
+        self.shape() == other.shape()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(left, right)| left.is_same(right))
+    }
+}
+
+impl<T> IsSame for Unit<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.as_ref().is_same(other.as_ref())
+    }
+}
+
+impl<T> IsSame for Quaternion<T>
+where
+    T: Scalar + IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.coords.is_same(&other.coords)
+    }
+}
+
+impl<T, D> IsSame for OPoint<T, D>
+where
+    T: Scalar + IsSame,
+    D: DimName,
+    DefaultAllocator: Allocator<D>,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.coords.is_same(&other.coords)
+    }
+}
+
+impl<T, const D: usize> IsSame for Translation<T, D>
+where
+    T: Scalar + IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.vector.is_same(&other.vector)
+    }
+}
+
+impl<T, const D: usize> IsSame for Rotation<T, D>
+where
+    T: Scalar + IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.matrix().is_same(other.matrix())
+    }
+}
+
+impl<T, R, const D: usize> IsSame for Isometry<T, R, D>
+where
+    T: Scalar + IsSame,
+    R: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.rotation.is_same(&other.rotation) && self.translation.is_same(&other.translation)
+    }
+}
+
+impl<T, R, const D: usize> IsSame for Similarity<T, R, D>
+where
+    T: Scalar + IsSame,
+    R: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.scaling().is_same(&other.scaling()) && self.isometry.is_same(&other.isometry)
+    }
+}
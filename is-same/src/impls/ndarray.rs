@@ -0,0 +1,26 @@
+use crate::IsSame;
+use ndarray::{ArrayBase, Data, Dimension};
+
+impl<A, B, S, S2, D, E> IsSame<ArrayBase<S2, E>> for ArrayBase<S, D>
+where
+    A: IsSame<B>,
+    S: Data<Elem = A>,
+    S2: Data<Elem = B>,
+    D: Dimension,
+    E: Dimension,
+{
+    fn is_same(&self, other: &ArrayBase<S2, E>) -> bool {
+        if self.shape() != other.shape() {
+            false
+        } else if core::mem::size_of::<A>() != 0
+            && core::ptr::addr_eq(self.as_ptr(), other.as_ptr())
+            && self.strides() == other.strides()
+        {
+            true
+        } else {
+            self.iter()
+                .zip(other.iter())
+                .all(|(left, right)| left.is_same(right))
+        }
+    }
+}
@@ -0,0 +1,5 @@
+use num_bigint::{BigInt, BigUint, Sign};
+
+simple_impl!(BigInt);
+simple_impl!(BigUint);
+simple_impl!(Sign);
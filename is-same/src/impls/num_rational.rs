@@ -0,0 +1,14 @@
+use crate::IsSame;
+use num_rational::Ratio;
+
+/// Compares the numerators and denominators as they are stored. Ratios
+/// are normally kept reduced, but ones built with `Ratio::new_raw` might
+/// not be, in which case `2/4` is not the same as `1/2`.
+impl<T> IsSame for Ratio<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.numer().is_same(other.numer()) && self.denom().is_same(other.denom())
+    }
+}
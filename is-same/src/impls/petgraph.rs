@@ -0,0 +1,77 @@
+use crate::IsSame;
+use petgraph::graph::{EdgeIndex, Graph, IndexType, NodeIndex};
+use petgraph::stable_graph::StableGraph;
+use petgraph::EdgeType;
+
+impl<Ix> IsSame for NodeIndex<Ix>
+where
+    Ix: IndexType,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+impl<Ix> IsSame for EdgeIndex<Ix>
+where
+    Ix: IndexType,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+/// Nodes and edges have to have the same indices, and edges have to
+/// connect the same nodes.
+impl<N, E, Ty, Ix> IsSame for Graph<N, E, Ty, Ix>
+where
+    N: IsSame,
+    E: IsSame,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        let left_nodes = self.raw_nodes();
+        let right_nodes = other.raw_nodes();
+        let left_edges = self.raw_edges();
+        let right_edges = other.raw_edges();
+        left_nodes.len() == right_nodes.len()
+            && left_edges.len() == right_edges.len()
+            && left_nodes
+                .iter()
+                .zip(right_nodes)
+                .all(|(left, right)| left.weight.is_same(&right.weight))
+            && left_edges.iter().zip(right_edges).all(|(left, right)| {
+                left.source() == right.source()
+                    && left.target() == right.target()
+                    && left.weight.is_same(&right.weight)
+            })
+    }
+}
+
+/// Like `Graph`, but only the occupied indices are compared, so removed
+/// nodes and edges don't have to line up.
+impl<N, E, Ty, Ix> IsSame for StableGraph<N, E, Ty, Ix>
+where
+    N: IsSame,
+    E: IsSame,
+    Ty: EdgeType,
+    Ix: IndexType,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.node_count() == other.node_count()
+            && self.edge_count() == other.edge_count()
+            && self
+                .node_indices()
+                .zip(other.node_indices())
+                .all(|(left, right)| left == right && self[left].is_same(&other[right]))
+            && self
+                .edge_indices()
+                .zip(other.edge_indices())
+                .all(|(left, right)| {
+                    left == right
+                        && self.edge_endpoints(left) == other.edge_endpoints(right)
+                        && self[left].is_same(&other[right])
+                })
+    }
+}
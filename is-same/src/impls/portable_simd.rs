@@ -0,0 +1,22 @@
+use crate::IsSame;
+use core::simd::{Mask, MaskElement, Simd, SimdElement};
+
+/// Compares the lanes with IsSame, so float lanes are compared by their
+/// bit patterns like scalar floats.
+impl<T, const N: usize> IsSame for Simd<T, N>
+where
+    T: SimdElement + IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.as_array().is_same(other.as_array())
+    }
+}
+
+impl<T, const N: usize> IsSame for Mask<T, N>
+where
+    T: MaskElement,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
@@ -0,0 +1,29 @@
+use crate::IsSame;
+use regex::{bytes, Regex, RegexSet};
+
+/// Compares the patterns the regexes were compiled from. Working out
+/// whether two different patterns match the same strings isn't feasible,
+/// so they're never the same even when they're equivalent.
+impl IsSame for Regex {
+    fn is_same(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl IsSame for bytes::Regex {
+    fn is_same(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl IsSame for RegexSet {
+    fn is_same(&self, other: &Self) -> bool {
+        self.patterns() == other.patterns()
+    }
+}
+
+impl IsSame for bytes::RegexSet {
+    fn is_same(&self, other: &Self) -> bool {
+        self.patterns() == other.patterns()
+    }
+}
@@ -0,0 +1,14 @@
+use crate::IsSame;
+use rust_decimal::Decimal;
+
+/// Compares the decimals by their representation rather than by value.
+///
+/// `1.0` and `1.00` are equal according to `PartialEq`, but have a
+/// different scale and display differently, so they are not the same.
+/// For the same reason `0` and `-0` are not the same, much like the
+/// float impls.
+impl IsSame for Decimal {
+    fn is_same(&self, other: &Self) -> bool {
+        self.serialize() == other.serialize()
+    }
+}
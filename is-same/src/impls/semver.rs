@@ -0,0 +1,8 @@
+use semver::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+
+simple_impl!(Version);
+simple_impl!(VersionReq);
+simple_impl!(Comparator);
+simple_impl!(Op);
+simple_impl!(Prerelease);
+simple_impl!(BuildMetadata);
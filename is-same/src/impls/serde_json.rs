@@ -0,0 +1,38 @@
+use crate::IsSame;
+use alloc::string::String;
+use serde_json::{Map, Number, Value};
+
+/// Floating point numbers are compared by their bit patterns, the same
+/// as `f64`. A float is never the same as an integer, even if they have
+/// the same value.
+impl IsSame for Number {
+    fn is_same(&self, other: &Self) -> bool {
+        if self.is_f64() && other.is_f64() {
+            self.as_f64().is_same(&other.as_f64())
+        } else {
+            self == other
+        }
+    }
+}
+
+impl IsSame for Map<String, Value> {
+    fn is_same(&self, other: &Self) -> bool {
+        super::unordered_map_is_same(self.iter(), self.len(), other.len(), |key| {
+            other.get_key_value(key)
+        })
+    }
+}
+
+impl IsSame for Value {
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Null, Value::Null) => true,
+            (Value::Bool(left), Value::Bool(right)) => left == right,
+            (Value::Number(left), Value::Number(right)) => left.is_same(right),
+            (Value::String(left), Value::String(right)) => left == right,
+            (Value::Array(left), Value::Array(right)) => left[..].is_same(&right[..]),
+            (Value::Object(left), Value::Object(right)) => left.is_same(right),
+            (_, _) => false,
+        }
+    }
+}
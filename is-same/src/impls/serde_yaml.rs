@@ -0,0 +1,60 @@
+use crate::IsSame;
+use serde_yaml::value::{Tag, TaggedValue};
+use serde_yaml::{Mapping, Number, Value};
+use std::collections::HashMap;
+
+/// Floating point numbers are compared by their bit patterns, the same
+/// as `f64`. A float is never the same as an integer, even if they have
+/// the same value.
+impl IsSame for Number {
+    fn is_same(&self, other: &Self) -> bool {
+        if self.is_f64() && other.is_f64() {
+            self.as_f64().is_same(&other.as_f64())
+        } else {
+            self == other
+        }
+    }
+}
+
+/// Entries are compared regardless of their order, like with
+/// `Mapping`'s own `PartialEq`.
+impl IsSame for Mapping {
+    fn is_same(&self, other: &Self) -> bool {
+        // Checked up front as well, to skip building the index.
+        if self.len() != other.len() {
+            return false;
+        }
+        // `Mapping` has no `get_key_value`, so the keys of `other` are
+        // indexed first to find the key each entry is stored under.
+        let entries = other
+            .iter()
+            .map(|(key, value)| (key, (key, value)))
+            .collect::<HashMap<_, _>>();
+        super::unordered_map_is_same(self.iter(), self.len(), other.len(), |key| {
+            entries.get(key).copied()
+        })
+    }
+}
+
+simple_impl!(Tag);
+
+impl IsSame for TaggedValue {
+    fn is_same(&self, other: &Self) -> bool {
+        self.tag == other.tag && self.value.is_same(&other.value)
+    }
+}
+
+impl IsSame for Value {
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Null, Value::Null) => true,
+            (Value::Bool(left), Value::Bool(right)) => left == right,
+            (Value::Number(left), Value::Number(right)) => left.is_same(right),
+            (Value::String(left), Value::String(right)) => left == right,
+            (Value::Sequence(left), Value::Sequence(right)) => left[..].is_same(&right[..]),
+            (Value::Mapping(left), Value::Mapping(right)) => left.is_same(right),
+            (Value::Tagged(left), Value::Tagged(right)) => left.is_same(right),
+            (_, _) => false,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::IsSame;
+use smol_str::SmolStr;
+
+/// Long strings are stored in an `Arc<str>`, so clones point at the same
+/// allocation and can skip comparing the contents.
+impl<Rhs> IsSame<Rhs> for SmolStr
+where
+    Rhs: AsRef<str> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        let left = self.as_str();
+        let right = other.as_ref();
+        (left.len() == right.len() && left.as_ptr() == right.as_ptr()) || left == right
+    }
+}
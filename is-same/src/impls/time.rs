@@ -0,0 +1,20 @@
+use crate::IsSame;
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// Two datetimes are the same if they refer to the same instant and
+/// have the same UTC offset. `PartialEq` only compares the instant.
+impl IsSame for OffsetDateTime {
+    fn is_same(&self, other: &Self) -> bool {
+        self.date() == other.date()
+            && self.time() == other.time()
+            && self.offset() == other.offset()
+    }
+}
+
+simple_impl!(PrimitiveDateTime);
+simple_impl!(Date);
+simple_impl!(Time);
+simple_impl!(Duration);
+simple_impl!(UtcOffset);
+simple_impl!(Month);
+simple_impl!(Weekday);
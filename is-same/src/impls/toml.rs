@@ -0,0 +1,32 @@
+use crate::IsSame;
+use alloc::string::String;
+use toml::map::Map;
+use toml::value::Datetime;
+use toml::Value;
+
+simple_impl!(Datetime);
+
+impl IsSame for Map<String, Value> {
+    fn is_same(&self, other: &Self) -> bool {
+        super::unordered_map_is_same(self.iter(), self.len(), other.len(), |key| {
+            other.get_key_value(key)
+        })
+    }
+}
+
+/// Floats are compared by their bit patterns, the same as `f64`. A float
+/// is never the same as an integer, even if they have the same value.
+impl IsSame for Value {
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::String(left), Value::String(right)) => left == right,
+            (Value::Integer(left), Value::Integer(right)) => left == right,
+            (Value::Float(left), Value::Float(right)) => left.is_same(right),
+            (Value::Boolean(left), Value::Boolean(right)) => left == right,
+            (Value::Datetime(left), Value::Datetime(right)) => left == right,
+            (Value::Array(left), Value::Array(right)) => left[..].is_same(&right[..]),
+            (Value::Table(left), Value::Table(right)) => left.is_same(right),
+            (_, _) => false,
+        }
+    }
+}
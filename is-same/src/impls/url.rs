@@ -0,0 +1,11 @@
+use crate::IsSame;
+use url::Url;
+
+impl<Rhs> IsSame<Rhs> for Url
+where
+    Rhs: AsRef<str> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        self.as_str() == other.as_ref()
+    }
+}
@@ -0,0 +1,3 @@
+use uuid::Uuid;
+
+simple_impl!(Uuid);
@@ -0,0 +1,18 @@
+use crate::IsSame;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = Object, js_name = is)]
+    fn object_is(left: &JsValue, right: &JsValue) -> bool;
+}
+
+/// Compares the values with `Object.is`. Objects are the same only if
+/// they're the same object, and primitives are compared like with
+/// `===`, except that `NaN` is the same as itself and `0` isn't the same
+/// as `-0`, matching how floats are compared in Rust.
+impl IsSame for JsValue {
+    fn is_same(&self, other: &Self) -> bool {
+        object_is(self, other)
+    }
+}
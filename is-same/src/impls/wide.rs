@@ -0,0 +1,35 @@
+use crate::trivial::{self, TriviallySame};
+use crate::IsSame;
+use wide::{
+    f32x16, f32x4, f32x8, f64x2, f64x4, f64x8, i16x16, i16x32, i16x8, i32x16, i32x4, i32x8, i64x2,
+    i64x4, i64x8, i8x16, i8x32, i8x64, u16x16, u16x32, u16x8, u32x16, u32x4, u32x8, u64x2, u64x4,
+    u64x8, u8x16, u8x32, u8x64,
+};
+
+/// Compares the lanes bitwise, so float lanes follow the same rules as
+/// `f32` and `f64`. The vectors are plain arrays of lanes with no
+/// padding, so they're also `TriviallySame`, which lets structs of them
+/// derive it.
+macro_rules! wide_impl {
+    ($($name:ident),*) => {
+        $(
+            impl IsSame for $name {
+                fn is_same(&self, other: &Self) -> bool {
+                    self.as_array().is_same(other.as_array())
+                }
+
+                fn is_same_slice(left: &[Self], right: &[Self]) -> bool {
+                    trivial::is_same_bytes(left, right)
+                }
+            }
+
+            unsafe impl TriviallySame for $name {}
+        )*
+    };
+}
+
+wide_impl!(f32x4, f32x8, f32x16, f64x2, f64x4, f64x8);
+wide_impl!(i8x16, i8x32, i8x64, i16x8, i16x16, i16x32);
+wide_impl!(i32x4, i32x8, i32x16, i64x2, i64x4, i64x8);
+wide_impl!(u8x16, u8x32, u8x64, u16x8, u16x16, u16x32);
+wide_impl!(u32x4, u32x8, u32x16, u64x2, u64x4, u64x8);
@@ -0,0 +1,118 @@
+//! Comparisons that can be split up over several calls.
+//!
+//! Comparing two very large snapshots can take longer than fits in a
+//! frame. A [`Comparison`] compares a limited number of elements each
+//! time it's advanced, and picks up where it left off the next time.
+//!
+//! ```
+//! use is_same::incremental::{Comparison, Status};
+//!
+//! let old = vec![1; 1000];
+//! let new = old.clone();
+//! let mut comparison = Comparison::new(&old, &new);
+//! let mut frames = 0;
+//! loop {
+//!     frames += 1;
+//!     match comparison.advance(300) {
+//!         Status::Pending => continue,
+//!         status => {
+//!             assert_eq!(status, Status::Same);
+//!             break;
+//!         }
+//!     }
+//! }
+//! assert_eq!(frames, 4);
+//! ```
+
+use crate::iter::lengths_can_match;
+use crate::IsSame;
+
+/// The result of advancing a `Comparison`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// Every element has been compared, and they were all the same.
+    Same,
+    /// A difference was found.
+    NotSame,
+    /// The budget ran out before the comparison finished.
+    Pending,
+}
+
+/// An element by element comparison of two sequences that can be run a
+/// bit at a time.
+///
+/// Both sides are iterated in order, so this works for anything that
+/// iterates deterministically, such as slices, `VecDeque`s and
+/// `BTreeMap`s, but not for hash maps. The collections can't be modified
+/// while they're borrowed by the comparison, so the result is the same as
+/// comparing them all at once.
+#[derive(Clone, Debug)]
+pub struct Comparison<L, R> {
+    left: L,
+    right: R,
+    status: Status,
+}
+
+impl<L, R> Comparison<L, R>
+where
+    L: Iterator,
+    R: Iterator,
+    L::Item: IsSame<R::Item>,
+{
+    /// Starts comparing two sequences. If their size hints show that the
+    /// lengths can't match, the comparison is already finished.
+    pub fn new<A, B>(left: A, right: B) -> Comparison<L, R>
+    where
+        A: IntoIterator<IntoIter = L>,
+        B: IntoIterator<IntoIter = R>,
+    {
+        let left = left.into_iter();
+        let right = right.into_iter();
+        let status = if lengths_can_match(left.size_hint(), right.size_hint()) {
+            Status::Pending
+        } else {
+            Status::NotSame
+        };
+        Comparison {
+            left,
+            right,
+            status,
+        }
+    }
+
+    /// Compares up to `budget` pairs of elements, returning `Pending` if
+    /// there are more left to compare. Once the comparison has finished,
+    /// this keeps returning the same result.
+    pub fn advance(&mut self, budget: usize) -> Status {
+        for _ in 0..budget {
+            if self.status != Status::Pending {
+                break;
+            }
+            self.status = match (self.left.next(), self.right.next()) {
+                (Some(left), Some(right)) => {
+                    if left.is_same(&right) {
+                        Status::Pending
+                    } else {
+                        Status::NotSame
+                    }
+                }
+                (None, None) => Status::Same,
+                _ => Status::NotSame,
+            };
+        }
+        self.status
+    }
+
+    /// Runs the comparison to the end.
+    pub fn finish(&mut self) -> Status {
+        while self.status == Status::Pending {
+            self.advance(usize::MAX);
+        }
+        self.status
+    }
+
+    /// Returns the status after the last call to `advance`.
+    pub fn status(&self) -> Status {
+        self.status
+    }
+}
@@ -0,0 +1,260 @@
+//! Interning values to get cheap identity comparisons.
+//!
+//! IsSame is fastest when it can compare pointers. Interning makes sure
+//! equal values share one allocation, so the handles can always be
+//! compared by pointer.
+
+use crate::IsSame;
+use std::borrow::Borrow;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Hands out one shared `Interned` handle for each distinct value.
+///
+/// ```
+/// use is_same::interner::Interner;
+/// use is_same::IsSame;
+///
+/// let mut interner = Interner::<str>::new();
+/// let a = interner.intern_from("bold");
+/// let b = interner.intern_from(&"bold".to_owned()[..]);
+/// assert!(a.is_same(&b));
+/// assert!(a.is_not_same(&interner.intern_from("italic")));
+/// ```
+pub struct Interner<T>
+where
+    T: ?Sized,
+{
+    values: HashSet<ByContents<T>>,
+}
+
+/// A handle to an interned value, compared by pointer.
+///
+/// Two handles from the same `Interner` point at the same allocation
+/// exactly when their values are equal, so `IsSame`, `PartialEq` and
+/// `Hash` only look at the pointer. Handles from different interners are
+/// never the same.
+pub struct Interned<T>(Arc<T>)
+where
+    T: ?Sized;
+
+// The set needs to look entries up by their contents, unlike the
+// handles that are given out.
+struct ByContents<T>(Arc<T>)
+where
+    T: ?Sized;
+
+impl<T> Interner<T>
+where
+    T: Hash + Eq + ?Sized,
+{
+    /// Creates an empty interner.
+    pub fn new() -> Interner<T> {
+        Interner {
+            values: HashSet::new(),
+        }
+    }
+
+    /// Returns the handle for `value`, interning it if it hasn't been
+    /// seen yet.
+    pub fn intern(&mut self, value: T) -> Interned<T>
+    where
+        T: Sized,
+    {
+        if let Some(existing) = self.values.get(&value) {
+            return Interned(existing.0.clone());
+        }
+        let value = Arc::new(value);
+        self.values.insert(ByContents(value.clone()));
+        Interned(value)
+    }
+
+    /// Returns the handle for a borrowed value, only allocating when it
+    /// hasn't been seen yet. This is also how unsized values like `str`
+    /// are interned.
+    pub fn intern_from<'a>(&mut self, value: &'a T) -> Interned<T>
+    where
+        Arc<T>: From<&'a T>,
+    {
+        if let Some(existing) = self.values.get(value) {
+            return Interned(existing.0.clone());
+        }
+        let value = Arc::<T>::from(value);
+        self.values.insert(ByContents(value.clone()));
+        Interned(value)
+    }
+
+    /// Returns the handle for `value` if it has already been interned.
+    pub fn get(&self, value: &T) -> Option<Interned<T>> {
+        self.values
+            .get(value)
+            .map(|existing| Interned(existing.0.clone()))
+    }
+
+    /// Drops the values that no handles point to anymore, and returns how
+    /// many were removed.
+    pub fn sweep(&mut self) -> usize {
+        let before = self.values.len();
+        self.values.retain(|value| Arc::strong_count(&value.0) > 1);
+        before - self.values.len()
+    }
+
+    /// Returns the number of distinct values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if nothing has been interned.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<T> Default for Interner<T>
+where
+    T: Hash + Eq + ?Sized,
+{
+    fn default() -> Interner<T> {
+        Interner::new()
+    }
+}
+
+impl<T> fmt::Debug for Interner<T>
+where
+    T: fmt::Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.values.iter().map(|value| &*value.0))
+            .finish()
+    }
+}
+
+impl<T> Interned<T>
+where
+    T: ?Sized,
+{
+    /// Returns the shared pointer to the value.
+    pub fn as_arc(&self) -> &Arc<T> {
+        &self.0
+    }
+
+    /// Unwraps the shared pointer to the value.
+    pub fn into_arc(self) -> Arc<T> {
+        self.0
+    }
+}
+
+impl<T> IsSame for Interned<T>
+where
+    T: ?Sized,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> PartialEq for Interned<T>
+where
+    T: ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T> Eq for Interned<T> where T: ?Sized {}
+
+impl<T> Hash for Interned<T>
+where
+    T: ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
+
+impl<T> Clone for Interned<T>
+where
+    T: ?Sized,
+{
+    fn clone(&self) -> Interned<T> {
+        Interned(self.0.clone())
+    }
+}
+
+impl<T> Deref for Interned<T>
+where
+    T: ?Sized,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> AsRef<T> for Interned<T>
+where
+    T: ?Sized,
+{
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> fmt::Debug for Interned<T>
+where
+    T: fmt::Debug + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (*self.0).fmt(f)
+    }
+}
+
+impl<T> fmt::Display for Interned<T>
+where
+    T: fmt::Display + ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (*self.0).fmt(f)
+    }
+}
+
+impl<T> PartialEq for ByContents<T>
+where
+    T: PartialEq + ?Sized,
+{
+    fn eq(&self, other: &Self) -> bool {
+        *self.0 == *other.0
+    }
+}
+
+impl<T> Eq for ByContents<T> where T: Eq + ?Sized {}
+
+impl<T> Hash for ByContents<T>
+where
+    T: Hash + ?Sized,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        (*self.0).hash(state);
+    }
+}
+
+impl<T> Borrow<T> for ByContents<T>
+where
+    T: ?Sized,
+{
+    fn borrow(&self) -> &T {
+        &self.0
+    }
+}
@@ -0,0 +1,61 @@
+//! Comparing iterators without collecting them first.
+
+use crate::IsSame;
+
+/// Compares two iterators element by element with IsSame.
+///
+/// Returns false without visiting any elements when the size hints show
+/// that the lengths can't match, so comparing iterators with exact sizes,
+/// such as slice iterators and their `map`s, is as cheap as comparing the
+/// lengths of the underlying collections.
+///
+/// ```
+/// use is_same::iter::iter_is_same;
+/// use std::collections::BTreeMap;
+///
+/// let map: BTreeMap<u32, f32> = (0..10).map(|i| (i, i as f32)).collect();
+/// let values = [5.0, 6.0, 7.0];
+/// assert!(iter_is_same(map.range(5..8).map(|(_, value)| value), values.iter()));
+/// ```
+pub fn iter_is_same<L, R>(left: L, right: R) -> bool
+where
+    L: IntoIterator,
+    R: IntoIterator,
+    L::Item: IsSame<R::Item>,
+{
+    iter_is_same_by(left, right, |left, right| left.is_same(&right))
+}
+
+/// Compares two iterators element by element with the given function,
+/// with the same size hint checks as `iter_is_same`.
+pub fn iter_is_same_by<L, R, F>(left: L, right: R, mut is_same: F) -> bool
+where
+    L: IntoIterator,
+    R: IntoIterator,
+    F: FnMut(L::Item, R::Item) -> bool,
+{
+    let mut left = left.into_iter();
+    let mut right = right.into_iter();
+    if !lengths_can_match(left.size_hint(), right.size_hint()) {
+        return false;
+    }
+    loop {
+        match (left.next(), right.next()) {
+            (Some(left), Some(right)) => {
+                if !is_same(left, right) {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+pub(crate) fn lengths_can_match(
+    left: (usize, Option<usize>),
+    right: (usize, Option<usize>),
+) -> bool {
+    let below = |lower: usize, upper: Option<usize>| upper.is_some_and(|upper| upper < lower);
+    !below(left.0, right.1) && !below(right.0, left.1)
+}
@@ -23,16 +23,125 @@
 //!     text: String,
 //! }
 //! ```
+//!
+//! Types from other crates that only need `==` can be given an impl
+//! with [`impl_is_same_via_partial_eq!`]. There's deliberately no blanket
+//! impl for every `T: Eq`, not even on nightly with specialization: the
+//! generic impls like `Vec<T>: IsSame<Vec<U>>` apply to types that aren't
+//! `Eq`, such as `Vec<f32>`, so they overlap with a blanket impl instead
+//! of specializing it, and the blanket impl would stop downstream crates
+//! from writing their own impls for their types.
+//!
+//! The crate is `no_std` when the default `std` feature is disabled.
+//! The `alloc` feature adds impls for the types from `alloc`, such as
+//! `Vec`, `String` and `Rc`.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
 #![forbid(missing_docs)]
 #![deny(clippy::all)]
 
-use std::any::TypeId;
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
-use std::hash::{BuildHasher, Hash};
-use std::path::{Path, PathBuf};
-use std::rc::Rc;
-use std::sync::Arc;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::alloc::Layout;
+use core::any::TypeId;
+use core::cmp::{Ordering, Reverse};
+use core::convert::Infallible;
+use core::ffi::CStr;
+use core::fmt::Alignment;
+use core::mem::ManuallyDrop;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use core::num::{
+    FpCategory, IntErrorKind, NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8,
+    NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    Saturating, Wrapping,
+};
+use core::ops::{
+    Bound, ControlFlow, Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
+use core::pin::Pin;
+use core::ptr::NonNull;
+use core::sync::atomic;
+use core::task::Poll;
+use core::time::Duration;
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    borrow::{Cow, ToOwned},
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    ffi::CString,
+    rc::Rc,
+    string::String,
+    vec::Vec,
+};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+#[cfg(feature = "std")]
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::{OsStr, OsString},
+    hash::{BuildHasher, Hash},
+    io,
+    net::Shutdown,
+    path::{Path, PathBuf},
+    process::ExitStatus,
+    sync::{Mutex, PoisonError, RwLock},
+    thread::ThreadId,
+    time::{Instant, SystemTime},
+};
+
+// Declared first so that its macros are in scope for the other modules.
+#[macro_use]
+mod hash_impls;
+
+pub mod adapters;
+mod assert;
+pub mod by;
+pub mod changed_fields;
+#[cfg(feature = "std")]
+pub mod channel;
+#[cfg(feature = "const_fn")]
+pub mod const_fn;
+pub mod diff;
+pub mod dynamic;
+pub mod fingerprint;
+#[cfg(feature = "alloc")]
+pub mod first_difference;
+#[cfg(feature = "alloc")]
+pub mod graph;
+pub mod incremental;
+#[cfg(feature = "std")]
+pub mod interner;
+pub mod iter;
+#[cfg(feature = "alloc")]
+pub mod list_diff;
+#[cfg(feature = "alloc")]
+pub mod map_delta;
+pub mod memo;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "pretty")]
+pub mod pretty;
+pub mod same_key;
+#[cfg(feature = "alloc")]
+pub mod sequence_diff;
+#[cfg(feature = "serde")]
+pub mod serialize;
+#[cfg(feature = "alloc")]
+pub mod signal;
+#[cfg(any(feature = "testing", feature = "proptest"))]
+pub mod testing;
+#[cfg(target_has_atomic = "64")]
+pub mod tracked;
+pub mod trivial;
+#[cfg(target_has_atomic = "64")]
+pub mod versioned;
+#[cfg(feature = "alloc")]
+pub mod visit;
 
 /// Compares two versions of a piece of data to see if it has changed.
 pub trait IsSame<Rhs = Self>
@@ -46,8 +155,30 @@ where
     fn is_not_same(&self, other: &Rhs) -> bool {
         !self.is_same(other)
     }
+
+    /// Compares two slices element by element. Slices with different
+    /// lengths are never the same.
+    ///
+    /// This is what the slice, array and `Vec` impls use, and it works
+    /// like `Hash::hash_slice`: types with a faster way of comparing many
+    /// values at once can override it. The types implemented with
+    /// `impl_is_same_via_partial_eq!`, including the integer types, use
+    /// the slice `==`, which compiles down to a memcmp for types like
+    /// `u8`.
+    fn is_same_slice(left: &[Self], right: &[Rhs]) -> bool
+    where
+        Self: Sized,
+        Rhs: Sized,
+    {
+        left.len() == right.len()
+            && left
+                .iter()
+                .zip(right.iter())
+                .all(|(left, right)| left.is_same(right))
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl<T> IsSame for Rc<T>
 where
     T: ?Sized,
@@ -57,6 +188,7 @@ where
     }
 }
 
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
 impl<T> IsSame for Arc<T>
 where
     T: ?Sized,
@@ -66,79 +198,176 @@ where
     }
 }
 
-impl<T, Rhs> IsSame<Rhs> for Vec<T>
+// Comparing a shared pointer against a plain value has no second pointer
+// to compare, so these compare the contents instead. That covers checking
+// an old `Arc<Config>` against a freshly parsed `Config`.
+#[cfg(feature = "alloc")]
+macro_rules! shared_pointee_impl {
+    ($ptr:ident) => {
+        impl<T> IsSame<T> for $ptr<T>
+        where
+            T: IsSame + ?Sized,
+        {
+            fn is_same(&self, other: &T) -> bool {
+                core::ptr::eq(&**self, other) || (**self).is_same(other)
+            }
+        }
+
+        impl<'a, T> IsSame<&'a T> for $ptr<T>
+        where
+            T: IsSame + ?Sized,
+        {
+            fn is_same(&self, other: &&'a T) -> bool {
+                self.is_same(*other)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "alloc")]
+shared_pointee_impl!(Rc);
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+shared_pointee_impl!(Arc);
+
+#[cfg(feature = "alloc")]
+impl<T> IsSame for VecDeque<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        // The two deques can wrap around their buffers at different
+        // points, so the iterators are used rather than comparing the
+        // halves returned by `as_slices()` directly.
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(left, right)| left.is_same(right))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IsSame for LinkedList<T>
 where
     T: IsSame,
-    Rhs: AsRef<[T]> + ?Sized,
 {
-    fn is_same(&self, other: &Rhs) -> bool {
-        let other = other.as_ref();
-        if self.as_ptr() == other.as_ptr() {
-            true
-        } else if self.len() != other.len() {
-            false
-        } else {
-            self.iter()
+    fn is_same(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
                 .zip(other.iter())
                 .all(|(left, right)| left.is_same(right))
-        }
     }
 }
 
-impl<Key, Value> IsSame for BTreeMap<Key, Value>
+#[cfg(feature = "alloc")]
+/// Compares the contents of the heaps, ignoring their internal layout.
+///
+/// Two heaps holding the same elements can store them in a different
+/// order depending on how they were built, so both sides are sorted
+/// before comparing, which allocates. Elements that are equal according
+/// to `Ord` are matched up by IsSame regardless of where the sort placed
+/// them.
+impl<T> IsSame for BinaryHeap<T>
 where
-    Key: IsSame + Ord,
-    Value: IsSame,
+    T: IsSame + Ord,
 {
     fn is_same(&self, other: &Self) -> bool {
-        let mut left = self.iter();
-        let mut right = other.iter();
-
-        loop {
-            let a = left.next();
-            let b = right.next();
-            match (a, b) {
-                (None, None) => return true,
-                (Some((left_key, left_val)), Some((right_key, right_val)))
-                    if left_key == right_key =>
-                {
-                    if left_val.is_not_same(right_val) {
-                        return false;
+        if self.len() != other.len() {
+            return false;
+        }
+        let mut left = self.iter().collect::<Vec<_>>();
+        let mut right = other.iter().collect::<Vec<_>>();
+        left.sort_unstable();
+        right.sort_unstable();
+
+        let mut start = 0;
+        while start < left.len() {
+            let key = left[start];
+            let end = start
+                + left[start..]
+                    .iter()
+                    .take_while(|value| (**value).cmp(key) == Ordering::Equal)
+                    .count();
+            // Both sides are sorted, so the run of equal elements has to
+            // cover the same positions on the right.
+            if right[start].cmp(key) != Ordering::Equal
+                || right[end - 1].cmp(key) != Ordering::Equal
+            {
+                return false;
+            }
+            if end - start == 1 {
+                if key.is_not_same(right[start]) {
+                    return false;
+                }
+            } else {
+                let mut matched = alloc::vec![false; end - start];
+                for value in &left[start..end] {
+                    let found = right[start..end]
+                        .iter()
+                        .zip(matched.iter_mut())
+                        .find(|(other, matched)| !**matched && value.is_same(other));
+                    match found {
+                        Some((_, matched)) => *matched = true,
+                        None => return false,
                     }
                 }
-                (_, _) => return false,
             }
+            start = end;
         }
+
+        true
     }
 }
 
+#[cfg(feature = "alloc")]
+/// Entries are compared in key order, with both the keys and the values
+/// compared using IsSame. `Ord` is only used to build the map, so keys that
+/// are equal but not the same, like two separately allocated `Rc`s with
+/// the same contents, make the maps not the same.
+impl<Key, Value> IsSame for BTreeMap<Key, Value>
+where
+    Key: IsSame,
+    Value: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self.iter().zip(other.iter()).all(
+                |((left_key, left_val), (right_key, right_val))| {
+                    left_key.is_same(right_key) && left_val.is_same(right_val)
+                },
+            )
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Like `BTreeMap`, the keys are compared in order using IsSame.
 impl<Key> IsSame for BTreeSet<Key>
 where
-    Key: IsSame + Ord,
+    Key: IsSame,
 {
     fn is_same(&self, other: &Self) -> bool {
-        let mut left = self.iter();
-        let mut right = other.iter();
-
-        loop {
-            let a = left.next();
-            let b = right.next();
-            match (a, b) {
-                (None, None) => return true,
-                (Some(left_key), Some(right_key)) if left_key == right_key => (),
-                (_, _) => return false,
-            }
-        }
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other.iter())
+                .all(|(left, right)| left.is_same(right))
     }
 }
 
-impl<Key, Value, State> IsSame for HashMap<Key, Value, State>
+#[cfg(feature = "std")]
+/// Entries are matched up by looking up each key of `self` in `other`,
+/// and then both the keys and the values are compared using IsSame. The
+/// two maps don't need to use the same hasher.
+impl<Key, Value, State, OtherState> IsSame<HashMap<Key, Value, OtherState>>
+    for HashMap<Key, Value, State>
 where
     Key: IsSame + Eq + Hash,
     Value: IsSame,
     State: BuildHasher,
+    OtherState: BuildHasher,
 {
-    fn is_same(&self, other: &Self) -> bool {
+    fn is_same(&self, other: &HashMap<Key, Value, OtherState>) -> bool {
         // Both a fast path and required to make sure we don't miss any
         // keys that exist in `other` but not `self`. Assumes that the
         // Key type has a non-broken PartialEq implementation, which
@@ -147,12 +376,13 @@ where
             return false;
         }
         for (left_key, left_val) in self {
-            if let Some(right_val) = other.get(left_key) {
-                if left_val.is_not_same(&right_val) {
-                    return false;
+            match other.get_key_value(left_key) {
+                Some((right_key, right_val)) => {
+                    if left_key.is_not_same(right_key) || left_val.is_not_same(right_val) {
+                        return false;
+                    }
                 }
-            } else {
-                return false;
+                None => return false,
             }
         }
 
@@ -160,13 +390,287 @@ where
     }
 }
 
-impl<Key, State> IsSame for HashSet<Key, State>
+#[cfg(feature = "std")]
+/// Like `HashMap`, keys are looked up with `Eq` and then compared using
+/// IsSame. The two sets don't need to use the same hasher.
+impl<Key, State, OtherState> IsSame<HashSet<Key, OtherState>> for HashSet<Key, State>
 where
     Key: IsSame + Eq + Hash,
     State: BuildHasher,
+    OtherState: BuildHasher,
+{
+    fn is_same(&self, other: &HashSet<Key, OtherState>) -> bool {
+        // As with HashMap, the length check makes sure `other` has no
+        // extra keys.
+        self.len() == other.len()
+            && self.iter().all(|key| match other.get(key) {
+                Some(other_key) => key.is_same(other_key),
+                None => false,
+            })
+    }
+}
+
+#[cfg(feature = "std")]
+/// Orders two references by address, so that locks can always be
+/// acquired in the same order regardless of which side is `self`.
+fn address_order<T>(left: &T, right: &T) -> bool
+where
+    T: ?Sized,
+{
+    (left as *const T).cast::<u8>() <= (right as *const T).cast::<u8>()
+}
+
+#[cfg(feature = "std")]
+/// Locks both mutexes and compares the guarded values.
+///
+/// The mutexes are always locked in address order, so two threads
+/// comparing the same pair can't deadlock against each other. Comparing
+/// a mutex against itself returns true without locking it. A poisoned
+/// mutex is compared using whatever value it holds, as the comparison
+/// only reads the data.
+impl<T> IsSame for Mutex<T>
+where
+    T: IsSame + ?Sized,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        if core::ptr::eq(self, other) {
+            return true;
+        }
+        let (left, right) = if address_order(self, other) {
+            let left = self.lock().unwrap_or_else(PoisonError::into_inner);
+            let right = other.lock().unwrap_or_else(PoisonError::into_inner);
+            (left, right)
+        } else {
+            let right = other.lock().unwrap_or_else(PoisonError::into_inner);
+            let left = self.lock().unwrap_or_else(PoisonError::into_inner);
+            (left, right)
+        };
+        left.is_same(&*right)
+    }
+}
+
+#[cfg(feature = "std")]
+/// Takes a read lock on both sides and compares the guarded values.
+///
+/// This follows the same rules as the `Mutex` impl: locks are taken in
+/// address order, comparing a lock against itself doesn't lock it, and
+/// poisoning is ignored.
+impl<T> IsSame for RwLock<T>
+where
+    T: IsSame + ?Sized,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        if core::ptr::eq(self, other) {
+            return true;
+        }
+        let (left, right) = if address_order(self, other) {
+            let left = self.read().unwrap_or_else(PoisonError::into_inner);
+            let right = other.read().unwrap_or_else(PoisonError::into_inner);
+            (left, right)
+        } else {
+            let right = other.read().unwrap_or_else(PoisonError::into_inner);
+            let left = self.read().unwrap_or_else(PoisonError::into_inner);
+            (left, right)
+        };
+        left.is_same(&*right)
+    }
+}
+
+/// Compares what the pinned pointers point to.
+///
+/// `Pin` doesn't give out references to the pointer it wraps, so this
+/// can't defer to the pointer's own impl. Instead it behaves like the
+/// `&T` impl: pointers to the same address are the same, otherwise the
+/// targets are compared. Note that this means `Pin<Rc<T>>` falls back to
+/// a deep comparison, unlike `Rc<T>`.
+impl<P, Q> IsSame<Pin<Q>> for Pin<P>
+where
+    P: Deref,
+    Q: Deref,
+    P::Target: IsSame<Q::Target>,
+{
+    fn is_same(&self, other: &Pin<Q>) -> bool {
+        let left = self.as_ref().get_ref();
+        let right = other.as_ref().get_ref();
+        core::ptr::addr_eq(left, right) || left.is_same(right)
+    }
+}
+
+impl<T> IsSame for ManuallyDrop<T>
+where
+    T: IsSame + ?Sized,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        (**self).is_same(&**other)
+    }
+}
+
+impl<T> IsSame for Wrapping<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.0.is_same(&other.0)
+    }
+}
+
+impl<T> IsSame for Saturating<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.0.is_same(&other.0)
+    }
+}
+
+impl<T> IsSame for Reverse<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.0.is_same(&other.0)
+    }
+}
+
+/// Compares the addresses currently stored in both atomics. Like the
+/// integer atomics, the pointers are loaded with `Ordering::Relaxed`.
+#[cfg(target_has_atomic = "ptr")]
+impl<T> IsSame for atomic::AtomicPtr<T> {
+    fn is_same(&self, other: &Self) -> bool {
+        self.load(atomic::Ordering::Relaxed) == other.load(atomic::Ordering::Relaxed)
+    }
+}
+
+impl<T> IsSame for Range<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.start.is_same(&other.start) && self.end.is_same(&other.end)
+    }
+}
+
+impl<T> IsSame for RangeInclusive<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.start().is_same(other.start()) && self.end().is_same(other.end())
+    }
+}
+
+impl<T> IsSame for RangeFrom<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.start.is_same(&other.start)
+    }
+}
+
+impl<T> IsSame for RangeTo<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.end.is_same(&other.end)
+    }
+}
+
+impl<T> IsSame for RangeToInclusive<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.end.is_same(&other.end)
+    }
+}
+
+impl<T> IsSame for Bound<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Bound::Included(left), Bound::Included(right)) => left.is_same(right),
+            (Bound::Excluded(left), Bound::Excluded(right)) => left.is_same(right),
+            (Bound::Unbounded, Bound::Unbounded) => true,
+            (_, _) => false,
+        }
+    }
+}
+
+impl<T, U> IsSame<Option<U>> for Option<T>
+where
+    T: IsSame<U>,
+{
+    fn is_same(&self, other: &Option<U>) -> bool {
+        match (self, other) {
+            (Some(left), Some(right)) => left.is_same(right),
+            (None, None) => true,
+            (_, _) => false,
+        }
+    }
+}
+
+impl<T, E, U, F> IsSame<Result<U, F>> for Result<T, E>
+where
+    T: IsSame<U>,
+    E: IsSame<F>,
+{
+    fn is_same(&self, other: &Result<U, F>) -> bool {
+        match (self, other) {
+            (Ok(left), Ok(right)) => left.is_same(right),
+            (Err(left), Err(right)) => left.is_same(right),
+            (_, _) => false,
+        }
+    }
+}
+
+impl IsSame for Infallible {
+    fn is_same(&self, _other: &Self) -> bool {
+        match *self {}
+    }
+}
+
+impl<B, C> IsSame for ControlFlow<B, C>
+where
+    B: IsSame,
+    C: IsSame,
 {
     fn is_same(&self, other: &Self) -> bool {
-        self == other
+        match (self, other) {
+            (ControlFlow::Continue(left), ControlFlow::Continue(right)) => left.is_same(right),
+            (ControlFlow::Break(left), ControlFlow::Break(right)) => left.is_same(right),
+            (_, _) => false,
+        }
+    }
+}
+
+impl<T> IsSame for Poll<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Poll::Ready(left), Poll::Ready(right)) => left.is_same(right),
+            (Poll::Pending, Poll::Pending) => true,
+            (_, _) => false,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+/// A best-effort comparison, as `io::Error` doesn't expose enough to do
+/// better. Two errors are the same if they have the same kind, the same
+/// raw OS error code (if any), and display the same message. Custom
+/// errors with the same message but different payloads will compare as
+/// the same.
+impl IsSame for io::Error {
+    fn is_same(&self, other: &Self) -> bool {
+        self.kind() == other.kind()
+            && self.raw_os_error() == other.raw_os_error()
+            && self.to_string() == other.to_string()
     }
 }
 
@@ -174,12 +678,20 @@ impl IsSame for f32 {
     fn is_same(&self, other: &Self) -> bool {
         self.to_bits() == other.to_bits()
     }
+
+    fn is_same_slice(left: &[Self], right: &[Self]) -> bool {
+        trivial::is_same_bytes(left, right)
+    }
 }
 
 impl IsSame for f64 {
     fn is_same(&self, other: &Self) -> bool {
         self.to_bits() == other.to_bits()
     }
+
+    fn is_same_slice(left: &[Self], right: &[Self]) -> bool {
+        trivial::is_same_bytes(left, right)
+    }
 }
 
 impl<'a, T> IsSame for &'a T
@@ -187,7 +699,7 @@ where
     T: IsSame + ?Sized + 'a,
 {
     fn is_same(&self, other: &Self) -> bool {
-        if (*self as *const T) == (*other as *const T) {
+        if core::ptr::eq(*self, *other) {
             true
         } else {
             (*self).is_same(other)
@@ -195,49 +707,298 @@ where
     }
 }
 
-impl<'a, T> IsSame for [T]
+/// Compares the pointers by address. For pointers to unsized types the
+/// metadata (slice length or vtable) has to match as well.
+impl<T> IsSame for *const T
+where
+    T: ?Sized,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        core::ptr::eq(*self, *other)
+    }
+}
+
+/// Compares the pointers by address, like the `*const T` impl.
+impl<T> IsSame for *mut T
 where
-    T: IsSame + 'a,
+    T: ?Sized,
 {
     fn is_same(&self, other: &Self) -> bool {
+        core::ptr::eq(*self, *other)
+    }
+}
+
+/// Compares the pointers by address, like the `*const T` impl.
+impl<T> IsSame for NonNull<T>
+where
+    T: ?Sized,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        core::ptr::eq(self.as_ptr(), other.as_ptr())
+    }
+}
+
+/// Compares the slices element by element, using `IsSame::is_same_slice`.
+/// Slices with the same length that start at the same address are the
+/// same without visiting any elements, which covers comparing a `Vec`
+/// against a slice of itself.
+impl<T, U> IsSame<[U]> for [T]
+where
+    T: IsSame<U>,
+{
+    fn is_same(&self, other: &[U]) -> bool {
         if self.len() != other.len() {
             false
+        } else if core::mem::size_of::<T>() != 0 && core::ptr::addr_eq(self, other) {
+            true
         } else {
-            for i in 0..self.len() {
-                if self[i].is_not_same(&other[i]) {
-                    return false;
-                }
+            <T as IsSame<U>>::is_same_slice(self, other)
+        }
+    }
+}
+
+macro_rules! sequence_impl {
+    ($([$($params:tt)*] $left:ty, $right:ty;)+) => {$(
+        impl<T, U, $($params)*> IsSame<$right> for $left
+        where
+            T: IsSame<U>,
+        {
+            fn is_same(&self, other: &$right) -> bool {
+                self[..].is_same(&other[..])
+            }
+        }
+    )+};
+}
+
+sequence_impl! {
+    [const M: usize] [T], [U; M];
+    [const N: usize] [T; N], [U];
+    [const N: usize, const M: usize] [T; N], [U; M];
+}
+
+#[cfg(feature = "alloc")]
+sequence_impl! {
+    [] Vec<T>, Vec<U>;
+    [] Vec<T>, [U];
+    [const M: usize] Vec<T>, [U; M];
+    [] [T], Vec<U>;
+    [const N: usize] [T; N], Vec<U>;
+}
+
+// Lets sequences be compared against borrowed forms of the other side,
+// such as `&[U]` or `Cow<[U]>`, without dereferencing them first.
+macro_rules! sequence_borrowed_impl {
+    ($([$($params:tt)*] $left:ty;)+) => {$(
+        impl<'a, T, R, $($params)*> IsSame<&'a R> for $left
+        where
+            $left: IsSame<R>,
+            R: ?Sized,
+        {
+            fn is_same(&self, other: &&'a R) -> bool {
+                self.is_same(*other)
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        impl<'a, T, R, $($params)*> IsSame<Cow<'a, R>> for $left
+        where
+            $left: IsSame<R>,
+            R: ToOwned + ?Sized,
+        {
+            fn is_same(&self, other: &Cow<'a, R>) -> bool {
+                self.is_same(&**other)
             }
-            true
         }
+    )+};
+}
+
+sequence_borrowed_impl! {
+    [] [T];
+    [const N: usize] [T; N];
+}
+
+#[cfg(feature = "alloc")]
+sequence_borrowed_impl! {
+    [] Vec<T>;
+}
+
+#[cfg(feature = "alloc")]
+impl<Rhs> IsSame<Rhs> for String
+where
+    Rhs: AsRef<str> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        self.as_str().is_same(other)
+    }
+}
+
+/// Strings that point at the same memory with the same length are the
+/// same without comparing their bytes. Like with slices, this makes
+/// comparing a `Cow` or `&str` against the data it borrows from cheap.
+impl<Rhs> IsSame<Rhs> for str
+where
+    Rhs: AsRef<str> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        let other = other.as_ref();
+        core::ptr::eq(self, other) || self == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Compares the borrowed contents, regardless of whether either side is
+/// owned or borrowed. The impls for `str`, slices, paths and the other
+/// unsized types check whether both sides point at the same data first,
+/// so two `Cow::Borrowed`s of the same data are the same without
+/// comparing their contents.
+impl<'a, B, Rhs> IsSame<Rhs> for Cow<'a, B>
+where
+    B: ToOwned + IsSame<Rhs> + ?Sized,
+    Rhs: ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        (**self).is_same(other)
     }
 }
 
+#[cfg(feature = "std")]
 impl<Rhs> IsSame<Rhs> for PathBuf
 where
     Rhs: AsRef<Path> + ?Sized,
 {
     fn is_same(&self, other: &Rhs) -> bool {
-        self == other.as_ref()
+        self.as_path().is_same(other)
     }
 }
 
+#[cfg(feature = "std")]
 impl<Rhs> IsSame<Rhs> for Path
 where
     Rhs: AsRef<Path> + ?Sized,
 {
     fn is_same(&self, other: &Rhs) -> bool {
-        self == other.as_ref()
+        let other = other.as_ref();
+        core::ptr::eq(self, other) || self == other
     }
 }
 
-macro_rules! simple_impl {
-    ($name:ty) => {
-        impl IsSame for $name {
+#[cfg(feature = "std")]
+impl<Rhs> IsSame<Rhs> for OsString
+where
+    Rhs: AsRef<OsStr> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        self.as_os_str().is_same(other)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Rhs> IsSame<Rhs> for OsStr
+where
+    Rhs: AsRef<OsStr> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        let other = other.as_ref();
+        core::ptr::eq(self, other) || self == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Rhs> IsSame<Rhs> for CString
+where
+    Rhs: AsRef<CStr> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        self.as_c_str().is_same(other)
+    }
+}
+
+impl<Rhs> IsSame<Rhs> for CStr
+where
+    Rhs: AsRef<CStr> + ?Sized,
+{
+    fn is_same(&self, other: &Rhs) -> bool {
+        let other = other.as_ref();
+        core::ptr::eq(self, other) || self == other
+    }
+}
+
+/// Implements IsSame for each of the given types by comparing them with
+/// `==`.
+///
+/// This is meant for types that aren't from this crate and already have
+/// a sensible `PartialEq` implementation. Types containing floats should
+/// generally get a handwritten impl instead, since `==` treats NaN as
+/// not equal to itself.
+///
+/// ```
+/// use is_same::{impl_is_same_via_partial_eq, IsSame};
+///
+/// #[derive(PartialEq)]
+/// struct UserId(u64);
+///
+/// #[derive(PartialEq)]
+/// enum Role {
+///     Admin,
+///     Guest,
+/// }
+///
+/// impl_is_same_via_partial_eq!(UserId, Role);
+///
+/// assert!(UserId(1).is_same(&UserId(1)));
+/// assert!(Role::Admin.is_not_same(&Role::Guest));
+/// ```
+#[macro_export]
+macro_rules! impl_is_same_via_partial_eq {
+    ($($name:ty),+ $(,)?) => {$(
+        impl $crate::IsSame for $name {
             fn is_same(&self, other: &Self) -> bool {
                 self == other
             }
+
+            fn is_same_slice(left: &[Self], right: &[Self]) -> bool {
+                left == right
+            }
         }
+    )+};
+}
+
+/// Implements IsSame for tuple struct newtypes by comparing the wrapped
+/// value, and lets the newtype be compared directly against the wrapped
+/// type as well.
+///
+/// ```
+/// use is_same::{impl_is_same_newtype, IsSame};
+///
+/// struct Meters(f64);
+/// struct Seconds(f64);
+///
+/// impl_is_same_newtype!(Meters => f64, Seconds => f64);
+///
+/// assert!(Meters(f64::NAN).is_same(&Meters(f64::NAN)));
+/// assert!(Meters(1.0).is_same(&1.0));
+/// assert!(Seconds(0.0).is_not_same(&-0.0));
+/// ```
+#[macro_export]
+macro_rules! impl_is_same_newtype {
+    ($($name:ty => $inner:ty),+ $(,)?) => {$(
+        impl $crate::IsSame for $name {
+            fn is_same(&self, other: &Self) -> bool {
+                <$inner as $crate::IsSame>::is_same(&self.0, &other.0)
+            }
+        }
+
+        impl $crate::IsSame<$inner> for $name {
+            fn is_same(&self, other: &$inner) -> bool {
+                <$inner as $crate::IsSame>::is_same(&self.0, other)
+            }
+        }
+    )+};
+}
+
+macro_rules! simple_impl {
+    ($name:ty) => {
+        impl_is_same_via_partial_eq!($name);
     };
 }
 
@@ -256,104 +1017,193 @@ simple_impl!(isize);
 simple_impl!(bool);
 simple_impl!(char);
 simple_impl!(());
-simple_impl!(String);
-simple_impl!(str);
 simple_impl!(TypeId);
+simple_impl!(RangeFull);
+simple_impl!(Ordering);
+simple_impl!(Alignment);
+simple_impl!(FpCategory);
+simple_impl!(IntErrorKind);
+#[cfg(feature = "std")]
+simple_impl!(Shutdown);
+simple_impl!(atomic::Ordering);
+#[cfg(feature = "std")]
+simple_impl!(io::ErrorKind);
+#[cfg(feature = "std")]
+simple_impl!(ThreadId);
+#[cfg(feature = "std")]
+simple_impl!(ExitStatus);
+simple_impl!(Layout);
+simple_impl!(Duration);
+#[cfg(feature = "std")]
+simple_impl!(Instant);
+#[cfg(feature = "std")]
+simple_impl!(SystemTime);
+simple_impl!(IpAddr);
+simple_impl!(Ipv4Addr);
+simple_impl!(Ipv6Addr);
+simple_impl!(SocketAddr);
+simple_impl!(SocketAddrV4);
+simple_impl!(SocketAddrV6);
+simple_impl!(NonZeroU8);
+simple_impl!(NonZeroU16);
+simple_impl!(NonZeroU32);
+simple_impl!(NonZeroU64);
+simple_impl!(NonZeroU128);
+simple_impl!(NonZeroUsize);
+simple_impl!(NonZeroI8);
+simple_impl!(NonZeroI16);
+simple_impl!(NonZeroI32);
+simple_impl!(NonZeroI64);
+simple_impl!(NonZeroI128);
+simple_impl!(NonZeroIsize);
 
-macro_rules! tuple_impl {
-    ($($tyname:ident, $left:ident, $right:ident;)+) => {
-        impl<$($tyname),+> IsSame for ($($tyname,)+)
-        where
-            $($tyname : IsSame),+
-        {
+macro_rules! fn_impl {
+    ($($arg:ident),*) => {
+        fn_impl!(@impl fn($($arg),*) -> R; $($arg),*);
+        fn_impl!(@impl unsafe fn($($arg),*) -> R; $($arg),*);
+        fn_impl!(@impl extern "C" fn($($arg),*) -> R; $($arg),*);
+        fn_impl!(@impl unsafe extern "C" fn($($arg),*) -> R; $($arg),*);
+    };
+    (@impl $ty:ty; $($arg:ident),*) => {
+        /// Compares function pointers by address.
+        ///
+        /// The compiler doesn't guarantee that function addresses are
+        /// unique: the same function can end up with different addresses
+        /// in different codegen units, and identical functions may be
+        /// merged into one.
+        impl<R, $($arg),*> IsSame for $ty {
             fn is_same(&self, other: &Self) -> bool {
-                let ($(ref $left,)+) = self;
-                let ($(ref $right,)+) = other;
-                $( $left.is_same($right) )&&+
+                core::ptr::fn_addr_eq(*self, *other)
             }
         }
     };
 }
 
-tuple_impl! {
-    T1, left, right;
-}
-
-tuple_impl! {
-    T1, left1, right1;
-    T2, left2, right2;
-}
+fn_impl!();
+fn_impl!(A);
+fn_impl!(A, B);
+fn_impl!(A, B, C);
+fn_impl!(A, B, C, D);
+fn_impl!(A, B, C, D, E);
+fn_impl!(A, B, C, D, E, F);
+fn_impl!(A, B, C, D, E, F, G);
+fn_impl!(A, B, C, D, E, F, G, H);
+fn_impl!(A, B, C, D, E, F, G, H, I);
+fn_impl!(A, B, C, D, E, F, G, H, I, J);
+fn_impl!(A, B, C, D, E, F, G, H, I, J, K);
+fn_impl!(A, B, C, D, E, F, G, H, I, J, K, L);
 
-tuple_impl! {
-    T1, left1, right1;
-    T2, left2, right2;
-    T3, left3, right3;
-}
-
-tuple_impl! {
-    T1, left1, right1;
-    T2, left2, right2;
-    T3, left3, right3;
-    T4, left4, right4;
-}
-
-tuple_impl! {
-    T1, left1, right1;
-    T2, left2, right2;
-    T3, left3, right3;
-    T4, left4, right4;
-    T5, left5, right5;
+// Callbacks taking a reference, like `fn(&Event)`, are higher-ranked
+// over the lifetime and aren't matched by the generic impls above.
+// rustc flags the overlap with `fn(A)` in the `coherence_leak_check`
+// lint, but the two types are distinct.
+macro_rules! fn_ref_impl {
+    ($($ty:ty),+) => {$(
+        /// Compares function pointers by address.
+        #[allow(coherence_leak_check)]
+        impl<A, R> IsSame for $ty
+        where
+            A: ?Sized,
+        {
+            fn is_same(&self, other: &Self) -> bool {
+                core::ptr::fn_addr_eq(*self, *other)
+            }
+        }
+    )+};
 }
 
-tuple_impl! {
-    T1, left1, right1;
-    T2, left2, right2;
-    T3, left3, right3;
-    T4, left4, right4;
-    T5, left5, right5;
-    T6, left6, right6;
-}
+fn_ref_impl!(
+    fn(&A) -> R,
+    fn(&mut A) -> R,
+    unsafe fn(&A) -> R,
+    unsafe fn(&mut A) -> R,
+    extern "C" fn(&A) -> R,
+    extern "C" fn(&mut A) -> R,
+    unsafe extern "C" fn(&A) -> R,
+    unsafe extern "C" fn(&mut A) -> R
+);
 
-tuple_impl! {
-    T1, left1, right1;
-    T2, left2, right2;
-    T3, left3, right3;
-    T4, left4, right4;
-    T5, left5, right5;
-    T6, left6, right6;
-    T7, left7, right7;
+macro_rules! atomic_impl {
+    ($name:ident, $width:literal) => {
+        /// Compares the values currently stored in both atomics.
+        ///
+        /// The values are loaded with `Ordering::Relaxed`, so the result
+        /// reflects each atomic at some point during the comparison.
+        #[cfg(target_has_atomic = $width)]
+        impl IsSame for atomic::$name {
+            fn is_same(&self, other: &Self) -> bool {
+                self.load(atomic::Ordering::Relaxed) == other.load(atomic::Ordering::Relaxed)
+            }
+        }
+    };
 }
 
-tuple_impl! {
-    T1, left1, right1;
-    T2, left2, right2;
-    T3, left3, right3;
-    T4, left4, right4;
-    T5, left5, right5;
-    T6, left6, right6;
-    T7, left7, right7;
-    T8, left8, right8;
-}
+atomic_impl!(AtomicBool, "8");
+atomic_impl!(AtomicU8, "8");
+atomic_impl!(AtomicU16, "16");
+atomic_impl!(AtomicU32, "32");
+atomic_impl!(AtomicU64, "64");
+atomic_impl!(AtomicUsize, "ptr");
+atomic_impl!(AtomicI8, "8");
+atomic_impl!(AtomicI16, "16");
+atomic_impl!(AtomicI32, "32");
+atomic_impl!(AtomicI64, "64");
+atomic_impl!(AtomicIsize, "ptr");
 
-macro_rules! array_impl {
-    ($( $count:tt )+) => {$(
-        impl<T> IsSame for [T; $count]
+macro_rules! tuple_impl {
+    ($($tyname:ident, $rhsname:ident, $left:ident, $right:ident;)+) => {
+        impl<$($tyname, $rhsname),+> IsSame<($($rhsname,)+)> for ($($tyname,)+)
         where
-            T: IsSame,
+            $($tyname : IsSame<$rhsname>),+
         {
-            fn is_same(&self, other: &Self) -> bool {
-                for i in 0..$count {
-                    if self[i].is_not_same(&other[i]) {
-                        return false;
-                    }
+            fn is_same(&self, other: &($($rhsname,)+)) -> bool {
+                if core::mem::size_of::<Self>() != 0 && core::ptr::addr_eq(self, other) {
+                    return true;
                 }
-                true
+                let ($(ref $left,)+) = self;
+                let ($(ref $right,)+) = other;
+                $( $left.is_same($right) )&&+
             }
         }
-    )+};
+    };
 }
 
-array_impl!(
-    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19
-    20 21 22 23 24 25 26 27 28 29 30 31 32
-);
+/// Invokes `tuple_impl!` for every prefix of the given list, so that
+/// each tuple length up to the full list gets an impl.
+macro_rules! tuple_impls {
+    (@prefix [$($done:tt)*]) => {};
+    (@prefix [$($done:tt)*] $tyname:ident, $rhsname:ident, $left:ident, $right:ident; $($rest:tt)*) => {
+        tuple_impl! { $($done)* $tyname, $rhsname, $left, $right; }
+        tuple_impls! { @prefix [$($done)* $tyname, $rhsname, $left, $right;] $($rest)* }
+    };
+    ($($list:tt)+) => {
+        tuple_impls! { @prefix [] $($list)+ }
+    };
+}
+
+tuple_impls! {
+    T1, U1, left1, right1;
+    T2, U2, left2, right2;
+    T3, U3, left3, right3;
+    T4, U4, left4, right4;
+    T5, U5, left5, right5;
+    T6, U6, left6, right6;
+    T7, U7, left7, right7;
+    T8, U8, left8, right8;
+    T9, U9, left9, right9;
+    T10, U10, left10, right10;
+    T11, U11, left11, right11;
+    T12, U12, left12, right12;
+    T13, U13, left13, right13;
+    T14, U14, left14, right14;
+    T15, U15, left15, right15;
+    T16, U16, left16, right16;
+}
+
+// Used by the exported macros. Not part of the public API.
+#[doc(hidden)]
+#[path = "private.rs"]
+pub mod __private;
+
+// Declared last so that the impls can use the macros above.
+mod impls;
@@ -0,0 +1,173 @@
+//! Keyed list reconciliation, in the style of a virtual DOM.
+//!
+//! Elements are matched between the old and new lists by a key, and
+//! the result describes which keys were inserted, removed, moved, or
+//! updated in place. Updated is decided using IsSame.
+
+use crate::IsSame;
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+/// One entry in the edit script produced by `list_diff`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ListEdit<Key> {
+    /// The key only exists in the new list.
+    Inserted {
+        /// The key of the element.
+        key: Key,
+        /// Where the element is in the new list.
+        new_index: usize,
+    },
+    /// The key only exists in the old list.
+    Removed {
+        /// The key of the element.
+        key: Key,
+        /// Where the element was in the old list.
+        old_index: usize,
+    },
+    /// The key exists in both lists, but the element changed position
+    /// relative to the other elements.
+    Moved {
+        /// The key of the element.
+        key: Key,
+        /// Where the element was in the old list.
+        old_index: usize,
+        /// Where the element is in the new list.
+        new_index: usize,
+    },
+    /// The key exists in both lists, but the element isn't the same.
+    Updated {
+        /// The key of the element.
+        key: Key,
+        /// Where the element was in the old list.
+        old_index: usize,
+        /// Where the element is in the new list.
+        new_index: usize,
+    },
+}
+
+/// Produces the edits that turn `old` into `new`, matching elements up
+/// by the key returned from `key`.
+///
+/// Removals come first, in order of their old index, followed by the
+/// inserts, moves and updates in order of their new index. An element
+/// that was both moved and changed produces a `Moved` edit followed by
+/// an `Updated` edit.
+///
+/// As few elements as possible are reported as moved: the largest set
+/// of matched elements that kept their relative order stays put, and
+/// everything else moved. If a key appears more than once in a list,
+/// only the first occurrence is matched up and the rest are treated as
+/// removed or inserted.
+///
+/// ```
+/// use is_same::list_diff::{list_diff, ListEdit};
+///
+/// let old = [("a", 1), ("b", 2), ("c", 3)];
+/// let new = [("c", 3), ("a", 1), ("b", 5)];
+/// let edits = list_diff(&old, &new, |(key, _)| *key);
+/// assert_eq!(
+///     edits,
+///     vec![
+///         ListEdit::Moved { key: "c", old_index: 2, new_index: 0 },
+///         ListEdit::Updated { key: "b", old_index: 1, new_index: 2 },
+///     ]
+/// );
+/// ```
+pub fn list_diff<T, Key, F>(old: &[T], new: &[T], mut key: F) -> Vec<ListEdit<Key>>
+where
+    T: IsSame,
+    Key: Ord + Clone,
+    F: FnMut(&T) -> Key,
+{
+    let mut old_indices = BTreeMap::new();
+    let mut old_keys = Vec::with_capacity(old.len());
+    for (index, value) in old.iter().enumerate() {
+        let key = key(value);
+        old_indices.entry(key.clone()).or_insert(index);
+        old_keys.push(key);
+    }
+
+    let mut matched = vec![false; old.len()];
+    let mut new_keys = Vec::with_capacity(new.len());
+    // For each new element, the old index of the element it matched
+    // with, if any.
+    let mut sources = Vec::with_capacity(new.len());
+    for value in new {
+        let key = key(value);
+        let source = match old_indices.get(&key) {
+            Some(&index) if !matched[index] => {
+                matched[index] = true;
+                Some(index)
+            }
+            _ => None,
+        };
+        new_keys.push(key);
+        sources.push(source);
+    }
+
+    let mut edits = Vec::new();
+    for (old_index, key) in old_keys.into_iter().enumerate() {
+        if !matched[old_index] {
+            edits.push(ListEdit::Removed { key, old_index });
+        }
+    }
+
+    let stationary = longest_increasing(&sources);
+    for (new_index, (key, source)) in new_keys.into_iter().zip(sources).enumerate() {
+        let old_index = match source {
+            Some(old_index) => old_index,
+            None => {
+                edits.push(ListEdit::Inserted { key, new_index });
+                continue;
+            }
+        };
+        if !stationary[new_index] {
+            edits.push(ListEdit::Moved {
+                key: key.clone(),
+                old_index,
+                new_index,
+            });
+        }
+        if old[old_index].is_not_same(&new[new_index]) {
+            edits.push(ListEdit::Updated {
+                key,
+                old_index,
+                new_index,
+            });
+        }
+    }
+
+    edits
+}
+
+/// Marks the positions forming a longest strictly increasing
+/// subsequence of the matched old indices, ignoring unmatched ones.
+fn longest_increasing(sources: &[Option<usize>]) -> Vec<bool> {
+    // `tails[n]` is the position in `sources` of the smallest value that
+    // ends an increasing run of length `n + 1`.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut previous = vec![None; sources.len()];
+    for (position, source) in sources.iter().enumerate() {
+        let value = match source {
+            Some(value) => *value,
+            None => continue,
+        };
+        let length = tails.partition_point(|&tail| sources[tail] < Some(value));
+        if length > 0 {
+            previous[position] = Some(tails[length - 1]);
+        }
+        if length == tails.len() {
+            tails.push(position);
+        } else {
+            tails[length] = position;
+        }
+    }
+
+    let mut result = vec![false; sources.len()];
+    let mut position = tails.last().copied();
+    while let Some(current) = position {
+        result[current] = true;
+        position = previous[current];
+    }
+    result
+}
@@ -0,0 +1,141 @@
+//! Bucketing the keys of two maps by how they differ.
+
+use crate::IsSame;
+use alloc::{collections::btree_map, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::{
+    collections::{hash_map, HashMap},
+    hash::{BuildHasher, Hash},
+};
+
+/// The entries that differ between two maps, borrowed from the maps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MapDelta<'a, Key, Value> {
+    /// Entries that only exist in the new map.
+    pub added: Vec<(&'a Key, &'a Value)>,
+    /// Entries that only exist in the old map.
+    pub removed: Vec<(&'a Key, &'a Value)>,
+    /// Keys that exist in both maps with values that aren't the same,
+    /// along with the old and new values.
+    pub changed: Vec<(&'a Key, &'a Value, &'a Value)>,
+}
+
+impl<'a, Key, Value> MapDelta<'a, Key, Value> {
+    /// Returns true if the maps had no differences.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// The maps that `map_delta` can be used with.
+pub trait Map {
+    /// The key type.
+    type Key;
+    /// The value type.
+    type Value;
+    /// Iterator over the entries of the map.
+    type Iter<'a>: Iterator<Item = (&'a Self::Key, &'a Self::Value)>
+    where
+        Self: 'a;
+
+    /// Returns an iterator over the entries of the map.
+    fn entries(&self) -> Self::Iter<'_>;
+
+    /// Looks up the entry for a key, returning the key as it's stored in
+    /// the map along with its value.
+    fn lookup(&self, key: &Self::Key) -> Option<(&Self::Key, &Self::Value)>;
+}
+
+impl<Key, Value> Map for BTreeMap<Key, Value>
+where
+    Key: Ord,
+{
+    type Key = Key;
+    type Value = Value;
+    type Iter<'a>
+        = btree_map::Iter<'a, Key, Value>
+    where
+        Self: 'a;
+
+    fn entries(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+
+    fn lookup(&self, key: &Key) -> Option<(&Key, &Value)> {
+        self.get_key_value(key)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Key, Value, State> Map for HashMap<Key, Value, State>
+where
+    Key: Eq + Hash,
+    State: BuildHasher,
+{
+    type Key = Key;
+    type Value = Value;
+    type Iter<'a>
+        = hash_map::Iter<'a, Key, Value>
+    where
+        Self: 'a;
+
+    fn entries(&self) -> Self::Iter<'_> {
+        self.iter()
+    }
+
+    fn lookup(&self, key: &Key) -> Option<(&Key, &Value)> {
+        self.get_key_value(key)
+    }
+}
+
+/// Walks two maps and sorts the keys that differ into added, removed
+/// and changed, comparing values with IsSame.
+///
+/// Keys are matched up with IsSame too, so a key that's equal to one in
+/// the other map but not the same counts as removed and added.
+///
+/// Each bucket is in the iteration order of the map it came from, so
+/// sorted by key for `BTreeMap` and in no particular order for
+/// `HashMap`.
+///
+/// ```
+/// use is_same::map_delta::map_delta;
+/// use std::collections::BTreeMap;
+///
+/// let old = BTreeMap::from([("a", 1), ("b", 2)]);
+/// let new = BTreeMap::from([("b", 3), ("c", 4)]);
+/// let delta = map_delta(&old, &new);
+/// assert_eq!(delta.added, vec![(&"c", &4)]);
+/// assert_eq!(delta.removed, vec![(&"a", &1)]);
+/// assert_eq!(delta.changed, vec![(&"b", &2, &3)]);
+/// ```
+pub fn map_delta<'a, M>(old: &'a M, new: &'a M) -> MapDelta<'a, M::Key, M::Value>
+where
+    M: Map,
+    M::Key: IsSame,
+    M::Value: IsSame,
+{
+    let mut delta = MapDelta {
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+    };
+    for (key, old_value) in old.entries() {
+        match new.lookup(key) {
+            Some((new_key, new_value)) if key.is_same(new_key) => {
+                if old_value.is_not_same(new_value) {
+                    delta.changed.push((key, old_value, new_value));
+                }
+            }
+            _ => delta.removed.push((key, old_value)),
+        }
+    }
+    for (key, new_value) in new.entries() {
+        match old.lookup(key) {
+            Some((old_key, _)) if old_key.is_same(key) => (),
+            _ => delta.added.push((key, new_value)),
+        }
+    }
+    delta
+}
@@ -0,0 +1,240 @@
+//! Helpers for skipping a computation when its input hasn't changed.
+
+use crate::IsSame;
+
+#[cfg(feature = "std")]
+use std::{
+    collections::hash_map::{self, HashMap},
+    hash::Hash,
+};
+
+/// Returns the new input together with an output for it, only calling
+/// `f` if `new_input` isn't the same as `prev_input`. Otherwise
+/// `prev_output` is reused.
+///
+/// ```
+/// use is_same::memo::memo;
+///
+/// let (_, output) = memo(&vec![1, 2], vec![1, 2], 3, |input| input.iter().sum());
+/// assert_eq!(output, 3);
+/// ```
+pub fn memo<T, R>(prev_input: &T, new_input: T, prev_output: R, f: impl FnOnce(&T) -> R) -> (T, R)
+where
+    T: IsSame,
+{
+    let (input, output, _) = memo_with_status(prev_input, new_input, prev_output, f);
+    (input, output)
+}
+
+/// Like `memo`, but also returns whether `f` was called.
+pub fn memo_with_status<T, R>(
+    prev_input: &T,
+    new_input: T,
+    prev_output: R,
+    f: impl FnOnce(&T) -> R,
+) -> (T, R, bool)
+where
+    T: IsSame,
+{
+    if prev_input.is_same(&new_input) {
+        (new_input, prev_output, false)
+    } else {
+        let output = f(&new_input);
+        (new_input, output, true)
+    }
+}
+
+/// Caches the output of a computation along with the input it was
+/// computed from, and only recomputes when the input changes.
+///
+/// ```
+/// use is_same::memo::Memo;
+///
+/// let mut memo = Memo::new();
+/// assert_eq!(*memo.get_or_compute(vec![1, 2], |input| input.len()), 2);
+/// // The input is the same, so the closure isn't called.
+/// assert_eq!(*memo.get_or_compute(vec![1, 2], |_| unreachable!()), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Memo<I, O> {
+    state: Option<(I, O)>,
+}
+
+impl<I, O> Memo<I, O>
+where
+    I: IsSame,
+{
+    /// Creates an empty memo, which will compute the output on the
+    /// first call to `get_or_compute`.
+    pub fn new() -> Memo<I, O> {
+        Memo { state: None }
+    }
+
+    /// Returns the cached output if `input` is the same as the last
+    /// input, otherwise calls `f` and caches its output.
+    ///
+    /// When the input is the same, the previous input is kept and the
+    /// new one is dropped.
+    pub fn get_or_compute(&mut self, input: I, f: impl FnOnce(&I) -> O) -> &O {
+        let stale = match &self.state {
+            Some((prev_input, _)) => prev_input.is_not_same(&input),
+            None => true,
+        };
+        if stale {
+            let output = f(&input);
+            self.state = Some((input, output));
+        }
+        match &self.state {
+            Some((_, output)) => output,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns the last input, if anything has been computed yet.
+    pub fn input(&self) -> Option<&I> {
+        self.state.as_ref().map(|(input, _)| input)
+    }
+
+    /// Returns the cached output, if anything has been computed yet.
+    pub fn output(&self) -> Option<&O> {
+        self.state.as_ref().map(|(_, output)| output)
+    }
+
+    /// Forgets the cached input and output, so the next call to
+    /// `get_or_compute` always recomputes.
+    pub fn clear(&mut self) {
+        self.state = None;
+    }
+}
+
+impl<I, O> Default for Memo<I, O>
+where
+    I: IsSame,
+{
+    fn default() -> Memo<I, O> {
+        Memo::new()
+    }
+}
+
+/// A `Memo` for each of a set of keys, such as one per child component
+/// of a UI.
+///
+/// Entries that haven't been used since the last call to `sweep` are
+/// removed by it, so calling `sweep` once per frame or update drops the
+/// entries for keys that went away.
+///
+/// ```
+/// use is_same::memo::MemoMap;
+///
+/// let mut cache = MemoMap::new();
+/// for name in &["a", "b"] {
+///     cache.get_or_compute(*name, name.to_string(), |input| input.len());
+/// }
+/// cache.sweep();
+/// cache.get_or_compute("a", "a".to_owned(), |_| unreachable!());
+/// cache.sweep();
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct MemoMap<K, I, O> {
+    entries: HashMap<K, MemoEntry<I, O>>,
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+struct MemoEntry<I, O> {
+    input: I,
+    output: O,
+    used: bool,
+}
+
+#[cfg(feature = "std")]
+impl<K, I, O> MemoMap<K, I, O>
+where
+    K: Hash + Eq,
+    I: IsSame,
+{
+    /// Creates an empty map.
+    pub fn new() -> MemoMap<K, I, O> {
+        MemoMap {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the cached output for `key` if `input` is the same as the
+    /// last input for it, otherwise calls `f` and caches its output.
+    /// Either way, the entry is marked as used.
+    pub fn get_or_compute(&mut self, key: K, input: I, f: impl FnOnce(&I) -> O) -> &O {
+        match self.entries.entry(key) {
+            hash_map::Entry::Occupied(occupied) => {
+                let entry = occupied.into_mut();
+                if entry.input.is_not_same(&input) {
+                    entry.output = f(&input);
+                    entry.input = input;
+                }
+                entry.used = true;
+                &entry.output
+            }
+            hash_map::Entry::Vacant(vacant) => {
+                let output = f(&input);
+                let entry = vacant.insert(MemoEntry {
+                    input,
+                    output,
+                    used: true,
+                });
+                &entry.output
+            }
+        }
+    }
+
+    /// Returns the cached output for `key`, without marking it as used.
+    pub fn get(&self, key: &K) -> Option<&O> {
+        self.entries.get(key).map(|entry| &entry.output)
+    }
+
+    /// Removes the entry for `key`, returning its input and output.
+    pub fn remove(&mut self, key: &K) -> Option<(I, O)> {
+        self.entries
+            .remove(key)
+            .map(|entry| (entry.input, entry.output))
+    }
+
+    /// Removes every entry that hasn't been used by `get_or_compute`
+    /// since the last sweep, and returns how many were removed.
+    pub fn sweep(&mut self) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|_, entry| {
+            let used = entry.used;
+            entry.used = false;
+            used
+        });
+        before - self.entries.len()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, I, O> Default for MemoMap<K, I, O>
+where
+    K: Hash + Eq,
+    I: IsSame,
+{
+    fn default() -> MemoMap<K, I, O> {
+        MemoMap::new()
+    }
+}
@@ -0,0 +1,69 @@
+//! Comparing large collections on multiple threads with rayon.
+//!
+//! This is only worth it for collections with a lot of elements, or
+//! elements that are expensive to compare. The comparison stops early on
+//! all threads once one of them finds a difference.
+
+use crate::IsSame;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::vec::Vec;
+
+/// Parallel version of IsSame, with the same results.
+pub trait ParIsSame<Rhs = Self>
+where
+    Rhs: ?Sized,
+{
+    /// Returns true if the two values are identical, splitting the
+    /// work across rayon's thread pool.
+    fn par_is_same(&self, other: &Rhs) -> bool;
+}
+
+impl<T, U> ParIsSame<[U]> for [T]
+where
+    T: IsSame<U> + Sync,
+    U: Sync,
+{
+    fn par_is_same(&self, other: &[U]) -> bool {
+        if self.len() != other.len() {
+            false
+        } else if core::mem::size_of::<T>() != 0 && core::ptr::addr_eq(self, other) {
+            true
+        } else {
+            self.par_iter()
+                .zip(other.par_iter())
+                .all(|(left, right)| left.is_same(right))
+        }
+    }
+}
+
+impl<T, U> ParIsSame<Vec<U>> for Vec<T>
+where
+    T: IsSame<U> + Sync,
+    U: Sync,
+{
+    fn par_is_same(&self, other: &Vec<U>) -> bool {
+        self[..].par_is_same(&other[..])
+    }
+}
+
+impl<Key, Value, State, OtherState> ParIsSame<HashMap<Key, Value, OtherState>>
+    for HashMap<Key, Value, State>
+where
+    Key: IsSame + Eq + Hash + Sync,
+    Value: IsSame + Sync,
+    State: BuildHasher + Sync,
+    OtherState: BuildHasher + Sync,
+{
+    fn par_is_same(&self, other: &HashMap<Key, Value, OtherState>) -> bool {
+        // Entries are matched up the same way as in the IsSame impl.
+        self.len() == other.len()
+            && self
+                .par_iter()
+                .all(|(left_key, left)| match other.get_key_value(left_key) {
+                    Some((right_key, right)) => left_key.is_same(right_key) && left.is_same(right),
+                    None => false,
+                })
+    }
+}
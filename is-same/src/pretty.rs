@@ -0,0 +1,184 @@
+//! Line by line diffs of `Debug` output, for test failures.
+//!
+//! With the `pretty` feature, a failing `assert_same!` adds one of these
+//! diffs to its panic message, so a mismatch in a large structure shows
+//! up as a few highlighted lines.
+//!
+//! Lines only in the left value are prefixed with `-` and shown in red,
+//! and lines only in the right value are prefixed with `+` and shown in
+//! green. When a line was changed, the parts that differ are highlighted
+//! as well. Colors are left out when the `NO_COLOR` environment variable
+//! is set.
+
+use crate::sequence_diff::{sequence_diff, Edit};
+use std::fmt::{self, Write};
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const HIGHLIGHT: &str = "\x1b[7m";
+const NO_HIGHLIGHT: &str = "\x1b[27m";
+const RESET: &str = "\x1b[0m";
+
+/// A diff between the pretty printed `Debug` output of two values,
+/// rendered with `Display`.
+///
+/// ```
+/// use is_same::pretty;
+///
+/// let (left, right) = (vec![1, 2, 3], vec![1, 4, 3]);
+/// let diff = pretty::diff(&left, &right).color(false);
+/// assert_eq!(diff.to_string(), " [\n     1,\n-    2,\n+    4,\n     3,\n ]\n");
+/// ```
+#[derive(Clone, Copy)]
+pub struct PrettyDiff<'a> {
+    left: &'a dyn fmt::Debug,
+    right: &'a dyn fmt::Debug,
+    color: bool,
+}
+
+/// Creates a diff from `left` to `right`.
+pub fn diff<'a, L, R>(left: &'a L, right: &'a R) -> PrettyDiff<'a>
+where
+    L: fmt::Debug,
+    R: fmt::Debug,
+{
+    PrettyDiff::new(left, right)
+}
+
+impl<'a> PrettyDiff<'a> {
+    pub(crate) fn new(left: &'a dyn fmt::Debug, right: &'a dyn fmt::Debug) -> PrettyDiff<'a> {
+        PrettyDiff {
+            left,
+            right,
+            color: std::env::var_os("NO_COLOR").is_none(),
+        }
+    }
+
+    /// Sets whether to use terminal colors. By default they're used
+    /// unless `NO_COLOR` is set.
+    pub fn color(mut self, color: bool) -> PrettyDiff<'a> {
+        self.color = color;
+        self
+    }
+
+    fn line(&self, f: &mut fmt::Formatter<'_>, sign: char, line: &str) -> fmt::Result {
+        match (self.color, sign) {
+            (true, '-') => writeln!(f, "{}-{}{}", RED, line, RESET),
+            (true, '+') => writeln!(f, "{}+{}{}", GREEN, line, RESET),
+            _ => writeln!(f, "{}{}", sign, line),
+        }
+    }
+
+    // Writes a line that was changed into another, highlighting the
+    // characters that aren't in the other line.
+    fn changed_line(&self, f: &mut fmt::Formatter<'_>, old: &str, new: &str) -> fmt::Result {
+        if !self.color {
+            self.line(f, '-', old)?;
+            return self.line(f, '+', new);
+        }
+        let old = old.chars().collect::<Vec<_>>();
+        let new = new.chars().collect::<Vec<_>>();
+        let edits = sequence_diff(&old, &new);
+        highlighted_line(f, RED, '-', &old, edits.iter().filter_map(old_side))?;
+        highlighted_line(f, GREEN, '+', &new, edits.iter().filter_map(new_side))
+    }
+}
+
+fn highlighted_line(
+    f: &mut fmt::Formatter<'_>,
+    color: &str,
+    sign: char,
+    chars: &[char],
+    runs: impl Iterator<Item = (usize, usize, bool)>,
+) -> fmt::Result {
+    write!(f, "{}{}", color, sign)?;
+    for (start, len, changed) in runs {
+        if changed {
+            f.write_str(HIGHLIGHT)?;
+        }
+        for ch in &chars[start..start + len] {
+            f.write_char(*ch)?;
+        }
+        if changed {
+            f.write_str(NO_HIGHLIGHT)?;
+        }
+    }
+    writeln!(f, "{}", RESET)
+}
+
+// The part of the old line covered by an edit, and whether it changed.
+fn old_side(edit: &Edit) -> Option<(usize, usize, bool)> {
+    match *edit {
+        Edit::Equal { old_index, len, .. } => Some((old_index, len, false)),
+        Edit::Delete { old_index, len } => Some((old_index, len, true)),
+        Edit::Insert { .. } => None,
+    }
+}
+
+fn new_side(edit: &Edit) -> Option<(usize, usize, bool)> {
+    match *edit {
+        Edit::Equal { new_index, len, .. } => Some((new_index, len, false)),
+        Edit::Insert { new_index, len } => Some((new_index, len, true)),
+        Edit::Delete { .. } => None,
+    }
+}
+
+impl<'a> fmt::Display for PrettyDiff<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let left = format!("{:#?}", self.left);
+        let right = format!("{:#?}", self.right);
+        let left = left.lines().collect::<Vec<_>>();
+        let right = right.lines().collect::<Vec<_>>();
+        let edits = sequence_diff(&left, &right);
+
+        let mut index = 0;
+        while index < edits.len() {
+            match edits[index] {
+                Edit::Equal { old_index, len, .. } => {
+                    for line in &left[old_index..old_index + len] {
+                        self.line(f, ' ', line)?;
+                    }
+                }
+                Edit::Delete { old_index, len } => {
+                    let deleted = &left[old_index..old_index + len];
+                    // Deletes always come before the inserts they touch,
+                    // so a following insert means these lines changed.
+                    let inserted = match edits.get(index + 1) {
+                        Some(&Edit::Insert { new_index, len }) => {
+                            index += 1;
+                            &right[new_index..new_index + len]
+                        }
+                        _ => &[],
+                    };
+                    let paired = deleted.len().min(inserted.len());
+                    for (old, new) in deleted.iter().zip(inserted.iter()) {
+                        self.changed_line(f, old, new)?;
+                    }
+                    for line in &deleted[paired..] {
+                        self.line(f, '-', line)?;
+                    }
+                    for line in &inserted[paired..] {
+                        self.line(f, '+', line)?;
+                    }
+                }
+                Edit::Insert { new_index, len } => {
+                    for line in &right[new_index..new_index + len] {
+                        self.line(f, '+', line)?;
+                    }
+                }
+            }
+            index += 1;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> fmt::Debug for PrettyDiff<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PrettyDiff")
+            .field("left", self.left)
+            .field("right", self.right)
+            .field("color", &self.color)
+            .finish()
+    }
+}
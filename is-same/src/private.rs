@@ -0,0 +1,157 @@
+//! Support code for the exported macros. Nothing in here is part of the
+//! public API.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::first_difference::FirstDifference;
+
+/// The path to the first difference, if it can be found.
+#[cfg(feature = "alloc")]
+pub type Path = crate::first_difference::DiffPath;
+
+/// Stand-in for `DiffPath` when it isn't available.
+#[cfg(not(feature = "alloc"))]
+pub type Path = core::convert::Infallible;
+
+// The macros pick between these traits using autoref specialization:
+// the impl on `Probe` is preferred when its bounds hold, and otherwise
+// method resolution falls back to the impl on `&Probe`.
+
+pub struct Probe<'a, T>(pub &'a T)
+where
+    T: ?Sized;
+
+pub trait ViaDebug {
+    fn __debug(&self) -> Option<&dyn fmt::Debug>;
+}
+
+impl<'a, T> ViaDebug for Probe<'a, T>
+where
+    T: fmt::Debug,
+{
+    fn __debug(&self) -> Option<&dyn fmt::Debug> {
+        Some(self.0)
+    }
+}
+
+pub trait ViaNoDebug {
+    fn __debug(&self) -> Option<&dyn fmt::Debug>;
+}
+
+impl<'a, T> ViaNoDebug for &Probe<'a, T>
+where
+    T: ?Sized,
+{
+    fn __debug(&self) -> Option<&dyn fmt::Debug> {
+        None
+    }
+}
+
+pub struct PairProbe<'a, L, R>(pub &'a L, pub &'a R)
+where
+    L: ?Sized,
+    R: ?Sized;
+
+pub trait ViaFirstDifference {
+    fn __first_difference(&self) -> Option<Path>;
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> ViaFirstDifference for PairProbe<'a, T, T>
+where
+    T: FirstDifference + ?Sized,
+{
+    fn __first_difference(&self) -> Option<Path> {
+        self.0.first_difference(self.1)
+    }
+}
+
+pub trait ViaNoFirstDifference {
+    fn __first_difference(&self) -> Option<Path>;
+}
+
+impl<'a, L, R> ViaNoFirstDifference for &PairProbe<'a, L, R>
+where
+    L: ?Sized,
+    R: ?Sized,
+{
+    fn __first_difference(&self) -> Option<Path> {
+        None
+    }
+}
+
+struct Value<'a>(Option<&'a dyn fmt::Debug>);
+
+impl<'a> fmt::Display for Value<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(value) => write!(f, "{:?}", value),
+            None => f.write_str("(doesn't implement Debug)"),
+        }
+    }
+}
+
+// With the `pretty` feature, failed `assert_same!`s end with a line by
+// line diff of the two values.
+struct PrettyDiff<'a> {
+    method: &'a str,
+    left: Option<&'a dyn fmt::Debug>,
+    right: Option<&'a dyn fmt::Debug>,
+}
+
+impl<'a> fmt::Display for PrettyDiff<'a> {
+    #[cfg(feature = "pretty")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.method, self.left, self.right) {
+            ("is_same", Some(left), Some(right)) => write!(
+                f,
+                "\n  diff (- left, + right):\n{}",
+                crate::pretty::PrettyDiff::new(left, right)
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    #[cfg(not(feature = "pretty"))]
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let _ = (self.method, self.left, self.right);
+        Ok(())
+    }
+}
+
+#[cold]
+#[track_caller]
+pub fn assert_failed(
+    method: &str,
+    left: Option<&dyn fmt::Debug>,
+    right: Option<&dyn fmt::Debug>,
+    path: Option<Path>,
+    args: Option<fmt::Arguments<'_>>,
+) -> ! {
+    let diff = PrettyDiff {
+        method,
+        left,
+        right,
+    };
+    let left = Value(left);
+    let right = Value(right);
+    match (args, path) {
+        (Some(args), Some(path)) => panic!(
+            "assertion `left.{}(right)` failed: {}\n  left: {}\n right: {}\n  path: {}{}",
+            method, args, left, right, path, diff
+        ),
+        (Some(args), None) => panic!(
+            "assertion `left.{}(right)` failed: {}\n  left: {}\n right: {}{}",
+            method, args, left, right, diff
+        ),
+        (None, Some(path)) => panic!(
+            "assertion `left.{}(right)` failed\n  left: {}\n right: {}\n  path: {}{}",
+            method, left, right, path, diff
+        ),
+        (None, None) => panic!(
+            "assertion `left.{}(right)` failed\n  left: {}\n right: {}{}",
+            method, left, right, diff
+        ),
+    }
+}
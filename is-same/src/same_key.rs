@@ -0,0 +1,135 @@
+//! Using IsSame as the equality of hash map keys.
+//!
+//! `SameKey` wraps a value so that its `Eq` impl uses `is_same`, which
+//! lets values containing floats or `Rc`s be used as `HashMap` keys. The
+//! `Hash` impl comes from the `SameHash` trait, which hashes values in a
+//! way that's consistent with IsSame: floats are hashed by their bit
+//! patterns, and `Rc` and `Arc` by their addresses.
+//!
+//! Unlike with `fingerprint`, hashing an `Rc` doesn't look at its
+//! contents, so hashing a large shared structure is cheap. The downside
+//! is that the hashes change between runs, so they shouldn't be stored.
+//!
+//! ```
+//! use is_same::same_key::SameKey;
+//! use std::collections::HashMap;
+//!
+//! let mut cache = HashMap::new();
+//! cache.insert(SameKey((f64::NAN, 1)), "computed");
+//! assert_eq!(cache.get(&SameKey((f64::NAN, 1))), Some(&"computed"));
+//! assert_eq!(cache.get(&SameKey((-0.0, 1))), None);
+//! ```
+
+use crate::IsSame;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+
+/// Hashes a value consistently with its IsSame impl, so that values that
+/// are the same always have the same hash.
+///
+/// This can be derived for structs with `is-same-derive`, in which case
+/// each field is hashed in the order they're declared.
+pub trait SameHash {
+    /// Feeds the parts of the value that IsSame compares into `state`.
+    fn same_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher;
+}
+
+/// Wraps a value so that `Eq` and `Hash` follow its IsSame impl.
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct SameKey<T>(pub T);
+
+impl<T> SameKey<T> {
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> PartialEq for SameKey<T>
+where
+    T: IsSame,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.0.is_same(&other.0)
+    }
+}
+
+/// IsSame is expected to be reflexive, which is what makes this sound.
+impl<T> Eq for SameKey<T> where T: IsSame {}
+
+impl<T> Hash for SameKey<T>
+where
+    T: SameHash,
+{
+    fn hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        self.0.same_hash(state);
+    }
+}
+
+impl<T> IsSame for SameKey<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.0.is_same(&other.0)
+    }
+}
+
+impl<T> From<T> for SameKey<T> {
+    fn from(value: T) -> SameKey<T> {
+        SameKey(value)
+    }
+}
+
+impl<T> Deref for SameKey<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+// Only the `Rc` and `Arc` impls below differ from `SameFingerprint`.
+same_hash_impls!(SameHash, same_hash);
+
+/// Hashes the address, since `Rc`s are only the same if they point to
+/// the same allocation.
+#[cfg(feature = "alloc")]
+impl<T> SameHash for Rc<T>
+where
+    T: ?Sized,
+{
+    fn same_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write_usize(Rc::as_ptr(self) as *const () as usize);
+    }
+}
+
+/// Hashes the address, since `Arc`s are only the same if they point to
+/// the same allocation.
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<T> SameHash for Arc<T>
+where
+    T: ?Sized,
+{
+    fn same_hash<H>(&self, state: &mut H)
+    where
+        H: Hasher,
+    {
+        state.write_usize(Arc::as_ptr(self) as *const () as usize);
+    }
+}
@@ -0,0 +1,264 @@
+//! Minimal edit scripts between two sequences.
+//!
+//! This uses the linear space variant of Myers' diff algorithm, with
+//! elements compared using IsSame. That means sequences containing NaN
+//! or shared `Rc`s diff sensibly, unlike with `Eq` based diffs.
+
+use crate::IsSame;
+use alloc::{vec, vec::Vec};
+use core::ops::{Index, IndexMut, Range};
+
+/// A run of elements in an edit script produced by `sequence_diff`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Edit {
+    /// `len` elements are the same in both sequences.
+    Equal {
+        /// Where the run starts in the old sequence.
+        old_index: usize,
+        /// Where the run starts in the new sequence.
+        new_index: usize,
+        /// How many elements are in the run.
+        len: usize,
+    },
+    /// `len` elements were removed from the old sequence.
+    Delete {
+        /// Where the run starts in the old sequence.
+        old_index: usize,
+        /// How many elements are in the run.
+        len: usize,
+    },
+    /// `len` elements were added in the new sequence.
+    Insert {
+        /// Where the run starts in the new sequence.
+        new_index: usize,
+        /// How many elements are in the run.
+        len: usize,
+    },
+}
+
+/// Computes a minimal edit script that turns `old` into `new`.
+///
+/// The edits cover both sequences from start to end, in order. Adjacent
+/// edits of the same kind are merged into one run, and when a delete
+/// and an insert touch, the delete comes first.
+///
+/// ```
+/// use is_same::sequence_diff::{sequence_diff, Edit};
+///
+/// let edits = sequence_diff(&["a", "b", "c"], &["a", "x", "c"]);
+/// assert_eq!(
+///     edits,
+///     vec![
+///         Edit::Equal { old_index: 0, new_index: 0, len: 1 },
+///         Edit::Delete { old_index: 1, len: 1 },
+///         Edit::Insert { new_index: 1, len: 1 },
+///         Edit::Equal { old_index: 2, new_index: 2, len: 1 },
+///     ]
+/// );
+/// ```
+pub fn sequence_diff<T, U>(old: &[T], new: &[U]) -> Vec<Edit>
+where
+    T: IsSame<U>,
+{
+    let max_d = max_d(old.len(), new.len());
+    let mut state = State {
+        old,
+        new,
+        forward: V::new(max_d),
+        backward: V::new(max_d),
+        edits: Vec::new(),
+    };
+    state.conquer(0..old.len(), 0..new.len());
+    state.edits
+}
+
+fn max_d(old_len: usize, new_len: usize) -> usize {
+    (old_len + new_len).div_ceil(2) + 1
+}
+
+/// The furthest reaching x coordinate on each diagonal `k`, indexed
+/// from `-max_d` to `max_d`.
+struct V {
+    offset: isize,
+    values: Vec<usize>,
+}
+
+impl V {
+    fn new(max_d: usize) -> V {
+        V {
+            offset: max_d as isize,
+            values: vec![0; 2 * max_d],
+        }
+    }
+}
+
+impl Index<isize> for V {
+    type Output = usize;
+
+    fn index(&self, index: isize) -> &usize {
+        &self.values[(index + self.offset) as usize]
+    }
+}
+
+impl IndexMut<isize> for V {
+    fn index_mut(&mut self, index: isize) -> &mut usize {
+        &mut self.values[(index + self.offset) as usize]
+    }
+}
+
+struct State<'a, T, U> {
+    old: &'a [T],
+    new: &'a [U],
+    forward: V,
+    backward: V,
+    edits: Vec<Edit>,
+}
+
+impl<'a, T, U> State<'a, T, U>
+where
+    T: IsSame<U>,
+{
+    fn common_prefix_len(&self, old: Range<usize>, new: Range<usize>) -> usize {
+        self.old[old]
+            .iter()
+            .zip(&self.new[new])
+            .take_while(|(left, right)| left.is_same(right))
+            .count()
+    }
+
+    fn common_suffix_len(&self, old: Range<usize>, new: Range<usize>) -> usize {
+        self.old[old]
+            .iter()
+            .rev()
+            .zip(self.new[new].iter().rev())
+            .take_while(|(left, right)| left.is_same(right))
+            .count()
+    }
+
+    fn push(&mut self, edit: Edit) {
+        // Keep deletes before inserts, so a replaced run always shows up
+        // as a delete followed by an insert.
+        if let (Edit::Delete { .. }, Some(&Edit::Insert { .. })) = (edit, self.edits.last()) {
+            let insert = self.edits.pop().unwrap();
+            self.push(edit);
+            self.push(insert);
+            return;
+        }
+        let merged = match (self.edits.last_mut(), edit) {
+            (Some(Edit::Equal { len, .. }), Edit::Equal { len: more, .. })
+            | (Some(Edit::Delete { len, .. }), Edit::Delete { len: more, .. })
+            | (Some(Edit::Insert { len, .. }), Edit::Insert { len: more, .. }) => {
+                *len += more;
+                true
+            }
+            _ => false,
+        };
+        if !merged {
+            self.edits.push(edit);
+        }
+    }
+
+    /// Finds the start of the middle snake of the optimal path through
+    /// the given ranges, by searching forwards from the start and
+    /// backwards from the end until the two searches overlap.
+    fn find_middle_snake(&mut self, old: Range<usize>, new: Range<usize>) -> (usize, usize) {
+        let n = old.len();
+        let m = new.len();
+        let delta = n as isize - m as isize;
+        let odd = delta & 1 == 1;
+        self.forward[1] = 0;
+        self.backward[1] = 0;
+
+        for d in 0..max_d(n, m) as isize {
+            for k in (-d..=d).rev().step_by(2) {
+                let mut x = if k == -d || (k != d && self.forward[k - 1] < self.forward[k + 1]) {
+                    self.forward[k + 1]
+                } else {
+                    self.forward[k - 1] + 1
+                };
+                let y = (x as isize - k) as usize;
+                let (start_x, start_y) = (x, y);
+                if x < n && y < m {
+                    x += self.common_prefix_len(old.start + x..old.end, new.start + y..new.end);
+                }
+                self.forward[k] = x;
+                if odd
+                    && (k - delta).abs() < d
+                    && self.forward[k] + self.backward[-(k - delta)] >= n
+                {
+                    return (old.start + start_x, new.start + start_y);
+                }
+            }
+
+            for k in (-d..=d).rev().step_by(2) {
+                let mut x = if k == -d || (k != d && self.backward[k - 1] < self.backward[k + 1]) {
+                    self.backward[k + 1]
+                } else {
+                    self.backward[k - 1] + 1
+                };
+                let mut y = (x as isize - k) as usize;
+                if x < n && y < m {
+                    let advance = self.common_suffix_len(
+                        old.start..old.start + n - x,
+                        new.start..new.start + m - y,
+                    );
+                    x += advance;
+                    y += advance;
+                }
+                self.backward[k] = x;
+                if !odd
+                    && (k - delta).abs() <= d
+                    && self.backward[k] + self.forward[-(k - delta)] >= n
+                {
+                    return (old.start + n - x, new.start + m - y);
+                }
+            }
+        }
+
+        // The two searches always meet before `d` reaches `max_d`.
+        unreachable!()
+    }
+
+    fn conquer(&mut self, mut old: Range<usize>, mut new: Range<usize>) {
+        let prefix = self.common_prefix_len(old.clone(), new.clone());
+        if prefix > 0 {
+            self.push(Edit::Equal {
+                old_index: old.start,
+                new_index: new.start,
+                len: prefix,
+            });
+        }
+        old.start += prefix;
+        new.start += prefix;
+
+        let suffix = self.common_suffix_len(old.clone(), new.clone());
+        old.end -= suffix;
+        new.end -= suffix;
+
+        if old.is_empty() && new.is_empty() {
+            // Nothing left between the prefix and suffix.
+        } else if new.is_empty() {
+            self.push(Edit::Delete {
+                old_index: old.start,
+                len: old.len(),
+            });
+        } else if old.is_empty() {
+            self.push(Edit::Insert {
+                new_index: new.start,
+                len: new.len(),
+            });
+        } else {
+            let (x, y) = self.find_middle_snake(old.clone(), new.clone());
+            self.conquer(old.start..x, new.start..y);
+            self.conquer(x..old.end, y..new.end);
+        }
+
+        if suffix > 0 {
+            self.push(Edit::Equal {
+                old_index: old.end,
+                new_index: new.end,
+                len: suffix,
+            });
+        }
+    }
+}
@@ -0,0 +1,455 @@
+//! Comparing values by what they serialize to.
+//!
+//! For types from other crates that implement `Serialize` but not
+//! IsSame, such as generated API types, [`same_by_serialize`] and
+//! [`BySerde`] compare the serialized forms instead. This is slower than
+//! a real IsSame impl, but works for any type tree right away.
+//!
+//! Values are serialized to a compact binary form that keeps the IsSame
+//! rules for the basic types: floats are written as their bit patterns,
+//! so NaNs are the same as themselves and `0.0` isn't the same as
+//! `-0.0`. Struct fields are written along with their names, including
+//! fields left out with `skip_serializing_if`, so a field being present
+//! on one side and a different field on the other is caught. Maps are
+//! compared in the order they serialize their entries, so `HashMap`s
+//! with the same entries may not be the same.
+
+use crate::IsSame;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use serde::ser::{self, Serialize};
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+/// Returns true if the two values serialize to the same data. If either
+/// value fails to serialize, they aren't the same.
+///
+/// ```
+/// use is_same::serialize::same_by_serialize;
+///
+/// assert!(same_by_serialize(&vec![1.0, f64::NAN], &vec![1.0, f64::NAN]));
+/// assert!(!same_by_serialize(&Some(0.0), &Some(-0.0)));
+/// ```
+pub fn same_by_serialize<L, R>(left: &L, right: &R) -> bool
+where
+    L: Serialize + ?Sized,
+    R: Serialize + ?Sized,
+{
+    let mut buffer = Vec::new();
+    if left.serialize(&mut Serializer(&mut buffer)).is_err() {
+        return false;
+    }
+    // The right side is checked against the buffer as it's serialized,
+    // so a difference near the start stops the comparison early.
+    let mut compare = Compare {
+        expected: &buffer,
+        position: 0,
+    };
+    right.serialize(&mut Serializer(&mut compare)).is_ok() && compare.position == buffer.len()
+}
+
+/// Compares the wrapped value with `same_by_serialize`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BySerde<T>(pub T);
+
+impl<T> IsSame for BySerde<T>
+where
+    T: Serialize,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        same_by_serialize(&self.0, &other.0)
+    }
+}
+
+impl<T> BySerde<T> {
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for BySerde<T> {
+    fn from(value: T) -> BySerde<T> {
+        BySerde(value)
+    }
+}
+
+impl<T> Deref for BySerde<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for BySerde<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+#[derive(Debug)]
+enum Error {
+    Mismatch,
+    Custom(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Mismatch => f.write_str("serialized data differs"),
+            Error::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T>(message: T) -> Error
+    where
+        T: fmt::Display,
+    {
+        Error::Custom(message.to_string())
+    }
+}
+
+trait Sink {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error>;
+}
+
+impl Sink for Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+struct Compare<'a> {
+    expected: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Sink for Compare<'a> {
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        let end = self.position + bytes.len();
+        if self.expected.get(self.position..end) == Some(bytes) {
+            self.position = end;
+            Ok(())
+        } else {
+            Err(Error::Mismatch)
+        }
+    }
+}
+
+// Tags written before each element of a sequence or map, and at the end.
+// Using these instead of a length prefix means sequences of unknown
+// length don't need to be buffered.
+const ELEMENT: u8 = 1;
+const END: u8 = 0;
+
+// Tags written before each struct field.
+const FIELD: u8 = 2;
+const SKIPPED: u8 = 3;
+
+struct Serializer<'a, S>(&'a mut S);
+
+impl<'a, S> Serializer<'a, S>
+where
+    S: Sink,
+{
+    fn write(&mut self, bytes: &[u8]) -> Result<(), Error> {
+        self.0.write(bytes)
+    }
+
+    fn write_len(&mut self, len: usize) -> Result<(), Error> {
+        self.write(&(len as u64).to_le_bytes())
+    }
+}
+
+impl<'a, 'b, S> ser::Serializer for &'b mut Serializer<'a, S>
+where
+    S: Sink,
+{
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, value: bool) -> Result<(), Error> {
+        self.write(&[value as u8])
+    }
+
+    fn serialize_i8(self, value: i8) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_i16(self, value: i16) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_i32(self, value: i32) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_i64(self, value: i64) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_i128(self, value: i128) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_u8(self, value: u8) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_u16(self, value: u16) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_u32(self, value: u32) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_u64(self, value: u64) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_u128(self, value: u128) -> Result<(), Error> {
+        self.write(&value.to_le_bytes())
+    }
+
+    fn serialize_f32(self, value: f32) -> Result<(), Error> {
+        self.write(&value.to_bits().to_le_bytes())
+    }
+
+    fn serialize_f64(self, value: f64) -> Result<(), Error> {
+        self.write(&value.to_bits().to_le_bytes())
+    }
+
+    fn serialize_char(self, value: char) -> Result<(), Error> {
+        self.write(&u32::from(value).to_le_bytes())
+    }
+
+    fn serialize_str(self, value: &str) -> Result<(), Error> {
+        self.serialize_bytes(value.as_bytes())
+    }
+
+    fn serialize_bytes(self, value: &[u8]) -> Result<(), Error> {
+        self.write_len(value.len())?;
+        self.write(value)
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        self.write(&[0])
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.write(&[1])?;
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<(), Error> {
+        self.serialize_u32(variant_index)
+    }
+
+    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.serialize_u32(variant_index)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self, Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self, Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self, Error> {
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self, Error> {
+        self.serialize_u32(variant_index)?;
+        Ok(self)
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self, Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self, Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self, Error> {
+        self.serialize_u32(variant_index)?;
+        Ok(self)
+    }
+}
+
+impl<'a, 'b, S> ser::SerializeSeq for &'b mut Serializer<'a, S>
+where
+    S: Sink,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.write(&[ELEMENT])?;
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.write(&[END])
+    }
+}
+
+impl<'a, 'b, S> ser::SerializeMap for &'b mut Serializer<'a, S>
+where
+    S: Sink,
+{
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.write(&[ELEMENT])?;
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        self.write(&[END])
+    }
+}
+
+// Tuples always have the same number of elements for a given type, so
+// their elements are written one after another with no tags.
+macro_rules! tuple_fields_impl {
+    ($($trait:ident :: $method:ident),+) => {$(
+        impl<'a, 'b, S> ser::$trait for &'b mut Serializer<'a, S>
+        where
+            S: Sink,
+        {
+            type Ok = ();
+            type Error = Error;
+
+            fn $method<T>(&mut self, value: &T) -> Result<(), Error>
+            where
+                T: Serialize + ?Sized,
+            {
+                value.serialize(&mut **self)
+            }
+
+            fn end(self) -> Result<(), Error> {
+                Ok(())
+            }
+        }
+    )+};
+}
+
+tuple_fields_impl! {
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field
+}
+
+// Structs can leave fields out with `skip_serializing_if`, so each field
+// is written with its name, and skipped fields leave a marker. Otherwise
+// `{ a: Some(1), b: None }` and `{ a: None, b: Some(1) }` would both be
+// written as a single `1` when `None`s are skipped.
+macro_rules! struct_fields_impl {
+    ($($trait:ident),+) => {$(
+        impl<'a, 'b, S> ser::$trait for &'b mut Serializer<'a, S>
+        where
+            S: Sink,
+        {
+            type Ok = ();
+            type Error = Error;
+
+            fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Error>
+            where
+                T: Serialize + ?Sized,
+            {
+                self.write(&[FIELD])?;
+                ser::Serializer::serialize_str(&mut **self, key)?;
+                value.serialize(&mut **self)
+            }
+
+            fn skip_field(&mut self, key: &'static str) -> Result<(), Error> {
+                self.write(&[SKIPPED])?;
+                ser::Serializer::serialize_str(&mut **self, key)
+            }
+
+            fn end(self) -> Result<(), Error> {
+                self.write(&[END])
+            }
+        }
+    )+};
+}
+
+struct_fields_impl!(SerializeStruct, SerializeStructVariant);
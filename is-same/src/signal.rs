@@ -0,0 +1,126 @@
+//! An observable value that only notifies when it really changes.
+
+use crate::IsSame;
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::sync::mpsc::Sender;
+
+/// Identifies a subscriber, so that it can be removed again.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SubscriptionId(u64);
+
+// Returns false once the subscriber should be removed, such as when the
+// receiving end of a channel has been dropped.
+type Subscriber<'a, T> = Box<dyn FnMut(&T) -> bool + 'a>;
+
+/// Holds a value and calls its subscribers whenever it's set to a value
+/// that isn't the same as the current one.
+///
+/// ```
+/// use is_same::signal::Signal;
+/// use std::cell::Cell;
+///
+/// let updates = Cell::new(0);
+/// let mut signal = Signal::new(vec![1.0, f32::NAN]);
+/// signal.subscribe(|_| updates.set(updates.get() + 1));
+/// assert!(!signal.set(vec![1.0, f32::NAN]));
+/// assert!(signal.set(vec![1.0]));
+/// assert_eq!(updates.get(), 1);
+/// ```
+pub struct Signal<'a, T> {
+    value: T,
+    subscribers: Vec<(SubscriptionId, Subscriber<'a, T>)>,
+    next_id: u64,
+}
+
+impl<'a, T> Signal<'a, T>
+where
+    T: IsSame,
+{
+    /// Creates a signal holding `value`, with no subscribers.
+    pub fn new(value: T) -> Signal<'a, T> {
+        Signal {
+            value,
+            subscribers: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Returns the current value.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Replaces the value and notifies the subscribers, unless `value`
+    /// is the same as the current value, in which case it's dropped.
+    /// Returns whether the value changed.
+    pub fn set(&mut self, value: T) -> bool {
+        if self.value.is_same(&value) {
+            return false;
+        }
+        self.value = value;
+        let value = &self.value;
+        self.subscribers
+            .retain_mut(|(_, subscriber)| subscriber(value));
+        true
+    }
+
+    /// Calls `f` with the value whenever it changes. The subscriber
+    /// isn't called with the current value.
+    pub fn subscribe(&mut self, mut f: impl FnMut(&T) + 'a) -> SubscriptionId {
+        self.subscribe_inner(Box::new(move |value| {
+            f(value);
+            true
+        }))
+    }
+
+    /// Sends a clone of the value on `sender` whenever it changes. The
+    /// subscription is removed once the receiver is dropped.
+    #[cfg(feature = "std")]
+    pub fn subscribe_sender(&mut self, sender: Sender<T>) -> SubscriptionId
+    where
+        T: Clone + 'a,
+    {
+        self.subscribe_inner(Box::new(move |value: &T| {
+            sender.send(value.clone()).is_ok()
+        }))
+    }
+
+    fn subscribe_inner(&mut self, subscriber: Subscriber<'a, T>) -> SubscriptionId {
+        let id = SubscriptionId(self.next_id);
+        self.next_id += 1;
+        self.subscribers.push((id, subscriber));
+        id
+    }
+
+    /// Removes a subscriber. Returns false if it was already removed.
+    pub fn unsubscribe(&mut self, id: SubscriptionId) -> bool {
+        let len = self.subscribers.len();
+        self.subscribers.retain(|(other, _)| *other != id);
+        self.subscribers.len() != len
+    }
+
+    /// Returns the number of subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Unwraps the value, dropping the subscribers.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<'a, T> fmt::Debug for Signal<'a, T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Signal")
+            .field("value", &self.value)
+            .field("subscribers", &self.subscribers.len())
+            .finish()
+    }
+}
@@ -0,0 +1,126 @@
+//! Checks for hand-written IsSame impls.
+//!
+//! IsSame is meant to behave like an equivalence relation: every value
+//! is the same as itself, and the order of the arguments doesn't matter.
+//! It's easy to break this by accident in a manual impl, for example by
+//! comparing one field against the wrong field of the other side. These
+//! functions panic with a description of the problem when an impl
+//! breaks one of the rules, so they can be called from tests.
+//!
+//! With the `proptest` feature, [`check_laws`] runs all of them against
+//! values generated by a proptest strategy.
+
+use crate::IsSame;
+use core::fmt::Debug;
+
+/// Checks that `value` is the same as itself.
+pub fn check_reflexive<T>(value: &T)
+where
+    T: IsSame + Debug + ?Sized,
+{
+    if !value.is_same(value) {
+        panic!(
+            "IsSame is not reflexive: {:?} is not the same as itself",
+            value
+        );
+    }
+}
+
+/// Checks that comparing `left` against `right` gives the same result
+/// as comparing `right` against `left`.
+pub fn check_symmetric<T, U>(left: &T, right: &U)
+where
+    T: IsSame<U> + Debug + ?Sized,
+    U: IsSame<T> + Debug + ?Sized,
+{
+    let forwards = left.is_same(right);
+    let backwards = right.is_same(left);
+    if forwards != backwards {
+        panic!(
+            "IsSame is not symmetric: left.is_same(right) is {} but right.is_same(left) is {}\n  left: {:?}\n right: {:?}",
+            forwards, backwards, left, right
+        );
+    }
+}
+
+/// Checks that if `a` is the same as `b` and `b` is the same as `c`, then
+/// `a` is the same as `c`.
+pub fn check_transitive<T>(a: &T, b: &T, c: &T)
+where
+    T: IsSame + Debug + ?Sized,
+{
+    if a.is_same(b) && b.is_same(c) && !a.is_same(c) {
+        panic!(
+            "IsSame is not transitive: a is the same as b and b as c, but not a as c\n a: {:?}\n b: {:?}\n c: {:?}",
+            a, b, c
+        );
+    }
+}
+
+/// Checks that IsSame agrees with PartialEq for the two values.
+///
+/// This only holds for types where IsSame is meant to be equivalent to
+/// `==`, such as the ones implemented with `impl_is_same_via_partial_eq!`.
+/// Floats, or types containing them or `Rc`s, intentionally differ.
+pub fn check_matches_eq<T>(left: &T, right: &T)
+where
+    T: IsSame + PartialEq + Debug + ?Sized,
+{
+    let same = left.is_same(right);
+    let eq = left == right;
+    if same != eq {
+        panic!(
+            "IsSame doesn't match PartialEq: is_same is {} but == is {}\n  left: {:?}\n right: {:?}",
+            same, eq, left, right
+        );
+    }
+}
+
+/// Runs `check_reflexive`, `check_symmetric` and `check_transitive`
+/// against values generated by `strategy`, failing with a minimized
+/// example if any of them panic.
+///
+/// Random values are rarely the same as each other, so strategies that
+/// only pick from a small set of values exercise the symmetric and
+/// transitive checks better.
+#[cfg(feature = "proptest")]
+pub fn check_laws<S>(strategy: S)
+where
+    S: proptest::strategy::Strategy + Clone,
+    S::Value: IsSame + Debug,
+{
+    check_laws_with(strategy, |_, _| {});
+}
+
+/// Like `check_laws`, but also runs `check_matches_eq` on each pair of
+/// generated values.
+#[cfg(feature = "proptest")]
+pub fn check_laws_against_eq<S>(strategy: S)
+where
+    S: proptest::strategy::Strategy + Clone,
+    S::Value: IsSame + PartialEq + Debug,
+{
+    check_laws_with(strategy, check_matches_eq);
+}
+
+#[cfg(feature = "proptest")]
+fn check_laws_with<S, F>(strategy: S, extra: F)
+where
+    S: proptest::strategy::Strategy + Clone,
+    S::Value: IsSame + Debug,
+    F: Fn(&S::Value, &S::Value),
+{
+    use proptest::test_runner::TestRunner;
+
+    let values = (strategy.clone(), strategy.clone(), strategy);
+    let result = TestRunner::default().run(&values, |(a, b, c)| {
+        check_reflexive(&a);
+        check_symmetric(&a, &b);
+        check_transitive(&a, &b, &c);
+        extra(&a, &b);
+        Ok(())
+    });
+    if let Err(error) = result {
+        panic!("{}", error);
+    }
+}
@@ -0,0 +1,122 @@
+//! A wrapper that records whether a value has been mutably borrowed.
+
+use crate::versioned::next_version;
+use crate::IsSame;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+/// Keeps a dirty flag that is set whenever the value is mutably
+/// borrowed, until it is cleared with `clear_dirty`.
+///
+/// Each value also has an id, which is kept by clones and replaced with
+/// a new, program-wide unique one when the dirty flag is cleared. Two
+/// `Tracked` values that are both clean and have the same id haven't
+/// been touched since one was cloned from the other, so comparing them
+/// doesn't look at the contents. Anything else falls back to comparing
+/// the contents.
+///
+/// ```
+/// use is_same::tracked::Tracked;
+/// use is_same::IsSame;
+///
+/// let mut current = Tracked::new(vec![1, 2, 3]);
+/// let snapshot = current.clone();
+/// current.push(4);
+/// assert!(current.is_dirty());
+/// current.clear_dirty();
+/// assert!(current.is_not_same(&snapshot));
+/// ```
+#[derive(Clone)]
+pub struct Tracked<T> {
+    id: u64,
+    dirty: bool,
+    value: T,
+}
+
+impl<T> Tracked<T> {
+    /// Wraps a value. It starts out clean.
+    pub fn new(value: T) -> Tracked<T> {
+        Tracked {
+            id: next_version(),
+            dirty: false,
+            value,
+        }
+    }
+
+    /// Returns true if the value has been mutably borrowed since it was
+    /// created or last cleared.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Clears the dirty flag. If it was set, the value also gets a new
+    /// id, so it no longer matches clones taken before it was modified.
+    pub fn clear_dirty(&mut self) {
+        if self.dirty {
+            self.dirty = false;
+            self.id = next_version();
+        }
+    }
+
+    /// Returns a mutable reference to the value and sets the dirty
+    /// flag. This is also what `DerefMut` does.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.dirty = true;
+        &mut self.value
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Tracked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Tracked<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T> Default for Tracked<T>
+where
+    T: Default,
+{
+    fn default() -> Tracked<T> {
+        Tracked::new(T::default())
+    }
+}
+
+impl<T> From<T> for Tracked<T> {
+    fn from(value: T) -> Tracked<T> {
+        Tracked::new(value)
+    }
+}
+
+impl<T> fmt::Debug for Tracked<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tracked")
+            .field("dirty", &self.dirty)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<T> IsSame for Tracked<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        (!self.dirty && !other.dirty && self.id == other.id) || self.value.is_same(&other.value)
+    }
+}
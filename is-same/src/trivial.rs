@@ -0,0 +1,82 @@
+//! Comparing slices of plain data with a single memcmp.
+//!
+//! Types like `#[repr(C)]` vertex or particle structs made entirely of
+//! numbers are the same exactly when their bytes are equal. Implementing
+//! [`TriviallySame`] for them, which `#[derive(TriviallySame)]` from
+//! is-same-derive does along with `IsSame`, lets slices and `Vec`s of them
+//! be compared as one block of bytes instead of field by field.
+//!
+//! A type implemented by hand can get the same fast path by overriding
+//! `IsSame::is_same_slice`:
+//!
+//! ```
+//! use is_same::trivial::{is_same_bytes, TriviallySame};
+//! use is_same::IsSame;
+//!
+//! #[derive(Clone, Copy)]
+//! #[repr(C)]
+//! struct Vertex {
+//!     position: [f32; 3],
+//!     color: u32,
+//! }
+//!
+//! // Safety: Vertex has no padding and IsSame compares every field
+//! // bitwise.
+//! unsafe impl TriviallySame for Vertex {}
+//!
+//! impl IsSame for Vertex {
+//!     fn is_same(&self, other: &Self) -> bool {
+//!         self.position.is_same(&other.position) && self.color == other.color
+//!     }
+//!
+//!     fn is_same_slice(left: &[Self], right: &[Self]) -> bool {
+//!         is_same_bytes(left, right)
+//!     }
+//! }
+//!
+//! let mesh = vec![Vertex { position: [0.0, 1.0, f32::NAN], color: 0 }; 100];
+//! assert!(mesh.is_same(&mesh.clone()));
+//! ```
+
+use crate::IsSame;
+
+/// Marker for types where IsSame is equivalent to comparing bytes.
+///
+/// # Safety
+///
+/// The type must not contain any padding or other uninitialized bytes,
+/// and `is_same` must return true exactly when the bytes of the two
+/// values are equal. This rules out references, pointers and anything
+/// that compares by more than its bits.
+pub unsafe trait TriviallySame: Copy + IsSame {}
+
+/// Compares two slices by comparing their bytes directly.
+pub fn is_same_bytes<T>(left: &[T], right: &[T]) -> bool
+where
+    T: TriviallySame,
+{
+    as_bytes(left) == as_bytes(right)
+}
+
+fn as_bytes<T>(values: &[T]) -> &[u8]
+where
+    T: TriviallySame,
+{
+    // Safety: TriviallySame types have no uninitialized bytes, so the
+    // whole slice can be read as bytes.
+    unsafe {
+        core::slice::from_raw_parts(values.as_ptr() as *const u8, core::mem::size_of_val(values))
+    }
+}
+
+macro_rules! trivial_impl {
+    ($($ty:ty),+) => {$(
+        unsafe impl TriviallySame for $ty {}
+    )+};
+}
+
+trivial_impl!(u8, u16, u32, u64, u128, usize);
+trivial_impl!(i8, i16, i32, i64, i128, isize);
+trivial_impl!(f32, f64, bool, char, ());
+
+unsafe impl<T, const N: usize> TriviallySame for [T; N] where T: TriviallySame {}
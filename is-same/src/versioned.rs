@@ -0,0 +1,116 @@
+//! A wrapper that makes comparing unchanged values O(1).
+
+use crate::IsSame;
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+static NEXT_VERSION: AtomicU64 = AtomicU64::new(0);
+
+pub(crate) fn next_version() -> u64 {
+    NEXT_VERSION.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Tags a value with a version that changes whenever it is mutably
+/// accessed.
+///
+/// Versions are unique across the whole program: every new value and
+/// every mutable access gets a version that nothing else has, while
+/// cloning keeps the version. Two `Versioned` values with the same
+/// version are therefore known to hold the same contents, and comparing
+/// them skips walking the value entirely. Values with different
+/// versions fall back to comparing their contents, since they may still
+/// be the same.
+///
+/// ```
+/// use is_same::versioned::Versioned;
+/// use is_same::IsSame;
+///
+/// let old = Versioned::new(vec![1, 2, 3]);
+/// let mut new = old.clone();
+/// assert_eq!(old.version(), new.version());
+/// new.push(4);
+/// assert!(old.is_not_same(&new));
+/// ```
+#[derive(Clone)]
+pub struct Versioned<T> {
+    version: u64,
+    value: T,
+}
+
+impl<T> Versioned<T> {
+    /// Wraps a value, giving it a new version.
+    pub fn new(value: T) -> Versioned<T> {
+        Versioned {
+            version: next_version(),
+            value,
+        }
+    }
+
+    /// Returns the current version.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns a mutable reference to the value, giving it a new
+    /// version. This is also what `DerefMut` does.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.version = next_version();
+        &mut self.value
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Versioned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Versioned<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.get_mut()
+    }
+}
+
+impl<T> Default for Versioned<T>
+where
+    T: Default,
+{
+    fn default() -> Versioned<T> {
+        Versioned::new(T::default())
+    }
+}
+
+impl<T> From<T> for Versioned<T> {
+    fn from(value: T) -> Versioned<T> {
+        Versioned::new(value)
+    }
+}
+
+impl<T> fmt::Debug for Versioned<T>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Versioned")
+            .field("version", &self.version)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+impl<T> IsSame for Versioned<T>
+where
+    T: IsSame,
+{
+    fn is_same(&self, other: &Self) -> bool {
+        self.version == other.version || self.value.is_same(&other.value)
+    }
+}
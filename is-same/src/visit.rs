@@ -0,0 +1,422 @@
+//! Comparisons that report what they're doing.
+//!
+//! `Visit` is a companion to IsSame that walks two values through a
+//! [`Walker`], which calls an [`IsSameVisitor`] when entering and leaving
+//! each field, index or key, and on each mismatch. That's enough to time
+//! the parts of a large comparison or log where mismatches come from,
+//! without writing the comparison twice.
+//!
+//! ```
+//! use is_same::first_difference::PathSegment;
+//! use is_same::visit::{visit_all, IsSameVisitor};
+//!
+//! #[derive(Default)]
+//! struct Mismatches(Vec<Vec<PathSegment>>);
+//!
+//! impl IsSameVisitor for Mismatches {
+//!     fn mismatch(&mut self, path: &[PathSegment]) {
+//!         self.0.push(path.to_vec());
+//!     }
+//! }
+//!
+//! let mut mismatches = Mismatches::default();
+//! assert!(!visit_all(&vec![1, 2, 3], &vec![1, 5, 6], &mut mismatches));
+//! assert_eq!(
+//!     mismatches.0,
+//!     vec![vec![PathSegment::Index(1)], vec![PathSegment::Index(2)]]
+//! );
+//! ```
+//!
+//! `Visit` can be derived for structs with `is-same-derive`, in which
+//! case fields are visited in the order they're declared.
+
+use crate::first_difference::PathSegment;
+use crate::map_delta::Map;
+use crate::IsSame;
+use alloc::{collections::BTreeMap, format, rc::Rc, string::String, vec::Vec};
+use core::fmt;
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+#[cfg(feature = "std")]
+use std::{
+    collections::HashMap,
+    hash::{BuildHasher, Hash},
+};
+
+/// Callbacks for a comparison made with `visit_is_same` or `visit_all`.
+///
+/// Paths are given from the root inwards, and are empty for the values
+/// themselves. All of the methods do nothing by default.
+pub trait IsSameVisitor {
+    /// Called before comparing the part of the values at `path`.
+    fn enter(&mut self, path: &[PathSegment]) {
+        let _ = path;
+    }
+
+    /// Called after comparing the part of the values at `path`, with
+    /// whether it was the same.
+    fn leave(&mut self, path: &[PathSegment], same: bool) {
+        let _ = (path, same);
+    }
+
+    /// Called where a difference originates, such as two numbers that
+    /// differ or a key that's missing from one side.
+    fn mismatch(&mut self, path: &[PathSegment]) {
+        let _ = path;
+    }
+}
+
+/// Compares two values while reporting to an `IsSameVisitor`.
+///
+/// This can be derived for structs with `is-same-derive`. The result has
+/// to agree with `is_same`.
+pub trait Visit: IsSame {
+    /// Compares `self` and `other` through `walker`, returning whether
+    /// they're the same.
+    fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool;
+}
+
+/// Drives a comparison, keeping track of the current path.
+pub struct Walker<'a> {
+    visitor: &'a mut dyn IsSameVisitor,
+    path: Vec<PathSegment>,
+    exhaustive: bool,
+}
+
+impl<'a> Walker<'a> {
+    /// Creates a walker that reports to `visitor`. If `exhaustive` is
+    /// false, impls stop at the first mismatch like `is_same` does.
+    pub fn new(visitor: &'a mut dyn IsSameVisitor, exhaustive: bool) -> Walker<'a> {
+        Walker {
+            visitor,
+            path: Vec::new(),
+            exhaustive,
+        }
+    }
+
+    /// Returns true if the rest of the values should still be visited
+    /// after a mismatch.
+    pub fn is_exhaustive(&self) -> bool {
+        self.exhaustive
+    }
+
+    /// Returns the path to the part of the values being compared.
+    pub fn path(&self) -> &[PathSegment] {
+        &self.path
+    }
+
+    /// Compares the values at a path segment below the current one.
+    pub fn child<T>(&mut self, segment: PathSegment, left: &T, right: &T) -> bool
+    where
+        T: Visit + ?Sized,
+    {
+        self.path.push(segment);
+        self.visitor.enter(&self.path);
+        let same = left.visit(right, self);
+        self.visitor.leave(&self.path, same);
+        self.path.pop();
+        same
+    }
+
+    /// Compares a struct field.
+    pub fn field<T>(&mut self, name: &'static str, left: &T, right: &T) -> bool
+    where
+        T: Visit + ?Sized,
+    {
+        self.child(PathSegment::Field(name), left, right)
+    }
+
+    /// Compares the element at an index of a sequence.
+    pub fn index<T>(&mut self, index: usize, left: &T, right: &T) -> bool
+    where
+        T: Visit + ?Sized,
+    {
+        self.child(PathSegment::Index(index), left, right)
+    }
+
+    /// Compares the values for a map key.
+    pub fn key<Key, T>(&mut self, key: &Key, left: &T, right: &T) -> bool
+    where
+        Key: fmt::Debug + ?Sized,
+        T: Visit + ?Sized,
+    {
+        self.child(PathSegment::Key(format!("{:?}", key)), left, right)
+    }
+
+    /// Compares values that have no parts to visit, reporting a mismatch
+    /// if they aren't the same.
+    pub fn leaf<T>(&mut self, left: &T, right: &T) -> bool
+    where
+        T: IsSame + ?Sized,
+    {
+        let same = left.is_same(right);
+        if !same {
+            self.mismatch();
+        }
+        same
+    }
+
+    /// Reports a mismatch at the current path and returns false.
+    pub fn mismatch(&mut self) -> bool {
+        self.visitor.mismatch(&self.path);
+        false
+    }
+
+    /// Reports a mismatch at a path segment below the current one, such
+    /// as an index or key that only exists on one side, and returns
+    /// false.
+    pub fn missing(&mut self, segment: PathSegment) -> bool {
+        self.path.push(segment);
+        self.visitor.enter(&self.path);
+        self.visitor.mismatch(&self.path);
+        self.visitor.leave(&self.path, false);
+        self.path.pop();
+        false
+    }
+}
+
+/// Compares two values like `is_same`, stopping at the first mismatch.
+pub fn visit_is_same<T, V>(left: &T, right: &T, visitor: &mut V) -> bool
+where
+    T: Visit + ?Sized,
+    V: IsSameVisitor,
+{
+    walk(left, right, visitor, false)
+}
+
+/// Compares two values, visiting every part of them even after a
+/// mismatch has been found.
+pub fn visit_all<T, V>(left: &T, right: &T, visitor: &mut V) -> bool
+where
+    T: Visit + ?Sized,
+    V: IsSameVisitor,
+{
+    walk(left, right, visitor, true)
+}
+
+fn walk<T, V>(left: &T, right: &T, visitor: &mut V, exhaustive: bool) -> bool
+where
+    T: Visit + ?Sized,
+    V: IsSameVisitor,
+{
+    let mut walker = Walker::new(visitor, exhaustive);
+    walker.visitor.enter(&[]);
+    let same = left.visit(right, &mut walker);
+    walker.visitor.leave(&[], same);
+    same
+}
+
+macro_rules! leaf_visit {
+    ($($name:ty),+) => {$(
+        impl Visit for $name {
+            fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+                walker.leaf(self, other)
+            }
+        }
+    )+};
+}
+
+leaf_visit!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+leaf_visit!(f32, f64, bool, char, (), str, String);
+
+/// Shared pointers are compared by address, so they're visited as a
+/// whole without looking inside.
+impl<T> Visit for Rc<T>
+where
+    T: ?Sized,
+{
+    fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+        walker.leaf(self, other)
+    }
+}
+
+/// Shared pointers are compared by address, so they're visited as a
+/// whole without looking inside.
+#[cfg(target_has_atomic = "ptr")]
+impl<T> Visit for Arc<T>
+where
+    T: ?Sized,
+{
+    fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+        walker.leaf(self, other)
+    }
+}
+
+/// When the lengths differ, the first index that only exists on one
+/// side is reported as a mismatch.
+impl<T> Visit for [T]
+where
+    T: Visit,
+{
+    fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+        let mut same = true;
+        for (index, (left, right)) in self.iter().zip(other.iter()).enumerate() {
+            if !walker.index(index, left, right) {
+                same = false;
+                if !walker.is_exhaustive() {
+                    return false;
+                }
+            }
+        }
+        if self.len() != other.len() {
+            same = walker.missing(PathSegment::Index(self.len().min(other.len())));
+        }
+        same
+    }
+}
+
+impl<T, const N: usize> Visit for [T; N]
+where
+    T: Visit,
+{
+    fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+        self[..].visit(&other[..], walker)
+    }
+}
+
+impl<T> Visit for Vec<T>
+where
+    T: Visit,
+{
+    fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+        self[..].visit(&other[..], walker)
+    }
+}
+
+/// The contents of two `Some`s are visited at the same path as the
+/// options themselves.
+impl<T> Visit for Option<T>
+where
+    T: Visit,
+{
+    fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+        match (self, other) {
+            (Some(left), Some(right)) => left.visit(right, walker),
+            (None, None) => true,
+            (_, _) => walker.mismatch(),
+        }
+    }
+}
+
+// Shared by the map impls. Keys that only exist on one side are
+// reported as missing, and keys are matched up with IsSame, so a key
+// that's equal but not the same is missing on both sides.
+fn visit_map<M>(left: &M, right: &M, walker: &mut Walker<'_>) -> bool
+where
+    M: Map,
+    M::Key: IsSame + fmt::Debug,
+    M::Value: Visit,
+{
+    let mut same = true;
+    for (key, left_value) in left.entries() {
+        let key_same = match right.lookup(key) {
+            Some((right_key, right_value)) if key.is_same(right_key) => {
+                walker.key(key, left_value, right_value)
+            }
+            _ => walker.missing(PathSegment::Key(format!("{:?}", key))),
+        };
+        if !key_same {
+            same = false;
+            if !walker.is_exhaustive() {
+                return false;
+            }
+        }
+    }
+    for (key, _) in right.entries() {
+        let found = match left.lookup(key) {
+            Some((left_key, _)) => left_key.is_same(key),
+            None => false,
+        };
+        if !found {
+            same = walker.missing(PathSegment::Key(format!("{:?}", key)));
+            if !walker.is_exhaustive() {
+                return false;
+            }
+        }
+    }
+    same
+}
+
+/// Keys that only exist on one side are reported as mismatches.
+impl<Key, Value> Visit for BTreeMap<Key, Value>
+where
+    Key: IsSame + Ord + fmt::Debug,
+    Value: Visit,
+{
+    fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+        visit_map(self, other, walker)
+    }
+}
+
+/// Keys that only exist on one side are reported as mismatches. Hash
+/// maps have no order, so the order entries are visited in isn't
+/// specified.
+#[cfg(feature = "std")]
+impl<Key, Value, State> Visit for HashMap<Key, Value, State>
+where
+    Key: IsSame + Eq + Hash + fmt::Debug,
+    Value: Visit,
+    State: BuildHasher,
+{
+    fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+        visit_map(self, other, walker)
+    }
+}
+
+macro_rules! tuple_visit {
+    ($($name:ident $index:tt),+) => {
+        /// Tuple elements are visited like fields named `0`, `1` and so
+        /// on.
+        impl<$($name),+> Visit for ($($name,)+)
+        where
+            $($name: Visit),+
+        {
+            fn visit(&self, other: &Self, walker: &mut Walker<'_>) -> bool {
+                let mut same = true;
+                $(
+                    if !walker.field(stringify!($index), &self.$index, &other.$index) {
+                        same = false;
+                        if !walker.is_exhaustive() {
+                            return false;
+                        }
+                    }
+                )+
+                same
+            }
+        }
+    };
+}
+
+/// Invokes `tuple_visit!` for every prefix of the given list, so that
+/// each tuple length up to the full list gets an impl.
+macro_rules! tuple_visits {
+    (@prefix [$($done:tt)*]) => {};
+    (@prefix [$($done:tt)*] $name:ident $index:tt; $($rest:tt)*) => {
+        tuple_visit!($($done)* $name $index);
+        tuple_visits! { @prefix [$($done)* $name $index,] $($rest)* }
+    };
+    ($($list:tt)+) => {
+        tuple_visits! { @prefix [] $($list)+ }
+    };
+}
+
+tuple_visits! {
+    A 0;
+    B 1;
+    C 2;
+    D 3;
+    E 4;
+    F 5;
+    G 6;
+    H 7;
+    I 8;
+    J 9;
+    K 10;
+    L 11;
+    M 12;
+    N 13;
+    O 14;
+    P 15;
+}
@@ -0,0 +1,73 @@
+use is_same::adapters::{ByEq, ByPtr, Deep};
+use is_same::IsSame;
+use std::rc::Rc;
+
+#[derive(PartialEq)]
+struct NotIsSame(f32);
+
+#[test]
+fn check_deep() {
+    let left = Rc::new(vec![1, 2]);
+    let right = Rc::new(vec![1, 2]);
+    assert!(left.is_not_same(&right));
+    assert!(Deep(left.clone()).is_same(&Deep(right)));
+    assert!(Deep(left).is_not_same(&Deep(Rc::new(vec![1]))));
+    assert!(Deep(Box::new(f32::NAN)).is_same(&Deep(Box::new(f32::NAN))));
+}
+
+#[test]
+fn check_deep_ptr_shortcut() {
+    // NaN is never equal to itself, so only the pointer check can make
+    // these the same.
+    let shared = Rc::new(ByEq(f32::NAN));
+    assert!(Deep(shared.clone()).is_same(&Deep(shared.clone())));
+    assert!(Deep(shared).is_not_same(&Deep(Rc::new(ByEq(f32::NAN)))));
+}
+
+#[test]
+fn check_by_eq() {
+    assert!(ByEq(NotIsSame(1.0)).is_same(&ByEq(NotIsSame(1.0))));
+    assert!(ByEq(NotIsSame(1.0)).is_not_same(&ByEq(NotIsSame(2.0))));
+    // NaN isn't equal to itself, so it's never the same via PartialEq.
+    assert!(ByEq(NotIsSame(f32::NAN)).is_not_same(&ByEq(NotIsSame(f32::NAN))));
+    assert!(ByEq(0.0).is_same(&ByEq(-0.0)));
+}
+
+#[test]
+fn check_by_ptr() {
+    let value = vec![1, 2];
+    assert!(ByPtr(&value).is_same(&ByPtr(&value)));
+    assert!(ByPtr(&value).is_not_same(&ByPtr(&value.clone())));
+    let boxed = ByPtr(Box::new(NotIsSame(1.0)));
+    assert!(boxed.is_same(&boxed));
+    assert!(boxed.is_not_same(&ByPtr(Box::new(NotIsSame(1.0)))));
+    assert_eq!(boxed.into_inner().0, 1.0);
+}
+
+#[test]
+fn check_approx() {
+    use is_same::adapters::{Approx, Tolerance};
+
+    struct Relative;
+
+    impl Tolerance for Relative {
+        const ABSOLUTE: f64 = 0.0;
+        const RELATIVE: f64 = 0.01;
+    }
+
+    let approx = |value: f64| Approx::<f64>::new(value);
+    assert!(approx(1.0).is_same(&approx(1.0 + 1e-7)));
+    assert!(approx(1.0).is_not_same(&approx(1.1)));
+    assert!(approx(0.0).is_same(&approx(-0.0)));
+    assert!(approx(f64::NAN).is_same(&approx(f64::NAN)));
+    assert!(approx(f64::NAN).is_not_same(&approx(1.0)));
+    assert!(approx(f64::INFINITY).is_same(&approx(f64::INFINITY)));
+    assert!(approx(f64::INFINITY).is_not_same(&approx(f64::NEG_INFINITY)));
+    assert!(approx(f64::INFINITY).is_not_same(&approx(f64::MAX)));
+
+    let relative = |value: f32| Approx::<f32, Relative>::new(value);
+    assert!(relative(1000.0).is_same(&relative(1005.0)));
+    assert!(relative(1000.0).is_not_same(&relative(1020.0)));
+    assert!(relative(0.0).is_not_same(&relative(1e-30)));
+    assert_eq!(relative(2.0).into_inner(), 2.0);
+}
@@ -0,0 +1,49 @@
+use is_same::{assert_not_same, assert_same, IsSame};
+
+struct Opaque(u8);
+
+impl IsSame for Opaque {
+    fn is_same(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[test]
+fn check_assert_same() {
+    assert_same!(1, 1);
+    assert_same!(f64::NAN, f64::NAN);
+    assert_same!("foo".to_owned(), "foo");
+    assert_same!(vec![1, 2], [1, 2], "message {}", 1);
+    assert_same!(Opaque(1), Opaque(1));
+    assert_not_same!(0.0, -0.0);
+    assert_not_same!(vec![1], vec![2], "message");
+    assert_not_same!(Opaque(1), Opaque(2));
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion `left.is_same(right)` failed\n  left: [[1, 2], [3]]\n right: [[1, 2], [4]]\n  path: [1][0]"
+)]
+fn check_assert_same_path() {
+    assert_same!(vec![vec![1, 2], vec![3]], vec![vec![1, 2], vec![4]]);
+}
+
+#[test]
+#[should_panic(
+    expected = "assertion `left.is_same(right)` failed: values for 1\n  left: \"foo\"\n right: \"bar\""
+)]
+fn check_assert_same_message() {
+    assert_same!("foo".to_owned(), "bar", "values for {}", 1);
+}
+
+#[test]
+#[should_panic(expected = "  left: (doesn't implement Debug)\n right: (doesn't implement Debug)")]
+fn check_assert_same_no_debug() {
+    assert_same!(Opaque(1), Opaque(2));
+}
+
+#[test]
+#[should_panic(expected = "assertion `left.is_not_same(right)` failed\n  left: 1.0\n right: 1.0")]
+fn check_assert_not_same() {
+    assert_not_same!(1.0, 1.0);
+}
@@ -0,0 +1,50 @@
+use is_same::by::{
+    option_same_by, option_same_by_key, same_by, same_by_key, slice_same_by, slice_same_by_key,
+};
+
+struct Point {
+    x: f32,
+    y: f32,
+    label: &'static str,
+}
+
+fn point(x: f32, y: f32, label: &'static str) -> Point {
+    Point { x, y, label }
+}
+
+#[test]
+fn values() {
+    let left = point(0.0, f32::NAN, "a");
+    let right = point(0.0, f32::NAN, "b");
+    assert!(same_by(&left, &right, |left, right| left.x == right.x));
+    assert!(same_by_key(&left, &right, |point| (point.x, point.y)));
+    assert!(!same_by_key(&left, &right, |point| point.label));
+    assert!(!same_by_key(&left, &point(-0.0, f32::NAN, "a"), |point| {
+        point.x
+    }));
+}
+
+#[test]
+fn options() {
+    let some = Some(point(1.0, 2.0, "a"));
+    assert!(option_same_by_key(
+        &some,
+        &Some(point(1.0, 2.0, "b")),
+        |point| &point.x
+    ));
+    assert!(option_same_by_key(&None, &None, |point: &Point| point.x));
+    assert!(!option_same_by_key(&some, &None, |point| point.x));
+    assert!(option_same_by(&Some(1), &Some("1"), |left, right| {
+        left.to_string() == *right
+    }));
+}
+
+#[test]
+fn slices() {
+    let left = vec![point(1.0, 2.0, "a"), point(3.0, 4.0, "b")];
+    let right = [point(1.0, 2.0, "a"), point(3.0, 5.0, "b")];
+    assert!(slice_same_by_key(&left, &right, |point| point.label));
+    assert!(!slice_same_by_key(&left, &right, |point| point.y));
+    assert!(!slice_same_by_key(&left[..1], &right, |point| point.label));
+    assert!(slice_same_by(&left, &[1.0, 3.0], |point, x| point.x == *x));
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "std")]
+
+use is_same::channel::DedupSender;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+#[test]
+fn mpsc_dedup() {
+    let (sender, receiver) = mpsc::channel();
+    let mut sender = DedupSender::new(sender);
+    assert!(sender.send(vec![f64::NAN]).unwrap());
+    assert!(!sender.send(vec![f64::NAN]).unwrap());
+    assert!(sender.send(vec![0.0]).unwrap());
+    assert!(sender.send(vec![-0.0]).unwrap());
+    assert_eq!(receiver.try_iter().count(), 3);
+
+    sender.reset();
+    assert!(sender.send(vec![-0.0]).unwrap());
+    assert_eq!(sender.last_sent().map(|last| last.len()), Some(1));
+
+    drop(receiver);
+    assert!(sender.send(vec![1.0]).is_err());
+    // The failed value wasn't remembered.
+    assert_eq!(
+        sender.last_sent().map(|last| last[0].to_bits()),
+        Some((-0.0f64).to_bits())
+    );
+}
+
+#[test]
+fn sync_sender() {
+    let (sender, receiver) = mpsc::sync_channel(4);
+    let mut sender = DedupSender::new(sender);
+    let snapshot = Arc::new("state".to_owned());
+    for _ in 0..10 {
+        sender.send(snapshot.clone()).unwrap();
+    }
+    sender.send(Arc::new("state".to_owned())).unwrap();
+    assert_eq!(receiver.try_iter().count(), 2);
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn tokio_watch() {
+    let (sender, mut receiver) = tokio::sync::watch::channel(0);
+    let mut sender = DedupSender::new(sender);
+    assert!(sender.send(1).unwrap());
+    assert!(receiver.has_changed().unwrap());
+    receiver.borrow_and_update();
+    assert!(!sender.send(1).unwrap());
+    assert!(!receiver.has_changed().unwrap());
+    assert!(sender.send(2).unwrap());
+    assert_eq!(*receiver.borrow_and_update(), 2);
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "const_fn")]
+
+use is_same::const_fn::*;
+
+const NAMES: [&str; 2] = ["red", "green"];
+const LEVELS: [f64; 3] = [0.0, 1.0, f64::NAN];
+
+const _: () = assert!(is_same_str(NAMES[1], "green"));
+const _: () = assert!(is_same_f64_slice(&LEVELS, &[0.0, 1.0, f64::NAN]));
+const _: () = assert!(!is_same_f64_slice(&LEVELS, &[0.0, 1.0]));
+
+#[test]
+fn primitives() {
+    assert!(is_same_u8(1, 1));
+    assert!(!is_same_i128(-1, 1));
+    assert!(is_same_bool(true, true));
+    assert!(!is_same_char('a', 'b'));
+    assert!(is_same_f32(f32::NAN, f32::NAN));
+    assert!(!is_same_f32(0.0, -0.0));
+    assert!(!is_same_f64(0.0, -0.0));
+    assert!(!is_same_str("red", "re"));
+}
+
+#[test]
+fn slices() {
+    assert!(is_same_u32_slice(&[], &[]));
+    assert!(is_same_char_slice(&['a', 'b'], &['a', 'b']));
+    assert!(!is_same_usize_slice(&[1, 2], &[1, 3]));
+    assert!(!is_same_f32_slice(&[0.0], &[-0.0]));
+}
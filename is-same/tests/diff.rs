@@ -0,0 +1,108 @@
+use is_same::diff::{ChangeSet, Diff, ElementChange, VecChange};
+use is_same::IsSame;
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[test]
+fn check_diff_values() {
+    assert_eq!(1u8.diff(&1), None);
+    assert_eq!(1u8.diff(&2), Some(2));
+    assert_eq!(f32::NAN.diff(&f32::NAN), None);
+    assert_eq!(
+        0.0f64.diff(&-0.0).map(f64::to_bits),
+        Some((-0.0f64).to_bits())
+    );
+    assert_eq!(
+        "foo".to_owned().diff(&"bar".to_owned()),
+        Some("bar".to_owned())
+    );
+}
+
+#[test]
+fn check_diff_vec() {
+    let left = vec![1, 2, 3];
+    assert_eq!(left.diff(&left.clone()), None);
+    assert_eq!(
+        left.diff(&vec![1, 5, 3, 4]),
+        Some(VecChange {
+            changes: vec![
+                (1, ElementChange::Modified(5)),
+                (3, ElementChange::Added(4))
+            ],
+        })
+    );
+    assert_eq!(
+        left.diff(&vec![1]),
+        Some(VecChange {
+            changes: vec![(1, ElementChange::Removed), (2, ElementChange::Removed)],
+        })
+    );
+}
+
+#[test]
+fn check_diff_maps() {
+    let mut left = BTreeMap::new();
+    left.insert("a", 1);
+    left.insert("b", 2);
+    left.insert("c", 3);
+    assert_eq!(left.diff(&left.clone()), None);
+    let mut right = left.clone();
+    right.remove("a");
+    right.insert("b", 5);
+    right.insert("d", 4);
+    assert_eq!(
+        left.diff(&right),
+        Some(ChangeSet {
+            added: vec![("d", 4)],
+            removed: vec!["a"],
+            modified: vec![("b", 5)],
+        })
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_diff_hash_maps() {
+    let left = HashMap::from([("a", 1), ("b", 2), ("c", 3)]);
+    let right = HashMap::from([("b", 5), ("c", 3), ("d", 4)]);
+    assert_eq!(left.diff(&left.clone()), None);
+    let changes = left.diff(&right).unwrap();
+    assert_eq!(changes.added, vec![("d", 4)]);
+    assert_eq!(changes.removed, vec!["a"]);
+    assert_eq!(changes.modified, vec![("b", 5)]);
+}
+
+// `Rc`s with equal contents are equal, but only the same if they share
+// an allocation, so the keys here have to be matched up with IsSame.
+#[test]
+fn check_diff_map_keys_use_is_same() {
+    use std::rc::Rc;
+
+    let key: Rc<str> = Rc::from("a");
+    let other_key: Rc<str> = Rc::from("a");
+    let left = BTreeMap::from([(key.clone(), 1u8)]);
+    assert!(left.diff(&BTreeMap::from([(key.clone(), 1u8)])).is_none());
+    let right = BTreeMap::from([(other_key.clone(), 1u8)]);
+    assert!(left.is_not_same(&right));
+    let change = left.diff(&right).unwrap();
+    assert!(Rc::ptr_eq(&change.removed[0], &key));
+    assert!(Rc::ptr_eq(&change.added[0].0, &other_key));
+    assert!(change.modified.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_diff_hash_map_keys_use_is_same() {
+    use std::rc::Rc;
+
+    let key: Rc<str> = Rc::from("a");
+    let other_key: Rc<str> = Rc::from("a");
+    let left = HashMap::from([(key.clone(), 1u8)]);
+    assert!(left.diff(&HashMap::from([(key.clone(), 1u8)])).is_none());
+    let right = HashMap::from([(other_key.clone(), 1u8)]);
+    assert!(left.is_not_same(&right));
+    let change = left.diff(&right).unwrap();
+    assert!(Rc::ptr_eq(&change.removed[0], &key));
+    assert!(Rc::ptr_eq(&change.added[0].0, &other_key));
+}
@@ -0,0 +1,25 @@
+use is_same::dynamic::IsSameDyn;
+use is_same::IsSame;
+use std::rc::Rc;
+
+#[test]
+fn check_is_same_dyn() {
+    let left: Box<dyn IsSameDyn> = Box::new(f32::NAN);
+    let right: Box<dyn IsSameDyn> = Box::new(f32::NAN);
+    let other: Box<dyn IsSameDyn> = Box::new(f64::NAN);
+    let different: Box<dyn IsSameDyn> = Box::new(1.0f32);
+    assert!(left.is_same(&right));
+    assert!(left.is_not_same(&other));
+    assert!(left.is_not_same(&different));
+    assert!(1u8.is_same_dyn(&1u8));
+    assert!(!1u8.is_same_dyn(&1u16));
+
+    let shared: Box<dyn IsSameDyn + Send + Sync> = Box::new(vec![1, 2]);
+    let other: Box<dyn IsSameDyn + Send + Sync> = Box::new(vec![1, 2]);
+    assert!(shared.is_same(&other));
+
+    let rc: Rc<dyn IsSameDyn> = Rc::new(1u8);
+    let other: Rc<dyn IsSameDyn> = Rc::new(1u8);
+    assert!(rc.is_same(&rc.clone()));
+    assert!(rc.is_not_same(&other));
+}
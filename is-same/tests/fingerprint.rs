@@ -0,0 +1,59 @@
+use is_same::fingerprint::{fingerprint, FingerprintHasher};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+use std::hash::Hasher;
+use std::rc::Rc;
+
+#[test]
+fn fnv_known_values() {
+    assert_eq!(FingerprintHasher::new().finish(), 0xcbf2_9ce4_8422_2325);
+    let mut hasher = FingerprintHasher::new();
+    hasher.write(b"a");
+    assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+}
+
+#[test]
+fn floats() {
+    assert_eq!(fingerprint(&f64::NAN), fingerprint(&f64::NAN));
+    assert_ne!(fingerprint(&0.0f64), fingerprint(&-0.0f64));
+    assert_eq!(fingerprint(&1.5f32), fingerprint(&1.5f32));
+}
+
+#[test]
+fn sequences() {
+    assert_eq!(fingerprint(&vec![1, 2, 3]), fingerprint(&[1, 2, 3]));
+    assert_ne!(fingerprint(&vec![1, 2, 3]), fingerprint(&vec![1, 3, 2]));
+    assert_ne!(
+        fingerprint(&vec![vec![1], vec![2, 3]]),
+        fingerprint(&vec![vec![1, 2], vec![3]])
+    );
+    assert_ne!(fingerprint(&Some(0u8)), fingerprint(&None::<u8>));
+    assert_eq!(fingerprint(&("a", 1)), fingerprint(&("a", 1)));
+
+    let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let mut other = tuple;
+    other.15 = 0;
+    assert_ne!(fingerprint(&tuple), fingerprint(&other));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map_order() {
+    let mut left = HashMap::new();
+    let mut right = HashMap::with_capacity(100);
+    for i in 0..50 {
+        left.insert(i, i * 2);
+        right.insert(49 - i, (49 - i) * 2);
+    }
+    assert_eq!(fingerprint(&left), fingerprint(&right));
+    right.insert(0, 1);
+    assert_ne!(fingerprint(&left), fingerprint(&right));
+}
+
+#[test]
+fn rc_contents() {
+    assert_eq!(
+        fingerprint(&Rc::new("foo".to_owned())),
+        fingerprint(&Rc::new("foo".to_owned()))
+    );
+}
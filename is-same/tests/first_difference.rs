@@ -0,0 +1,105 @@
+use is_same::first_difference::{DiffPath, FirstDifference, PathSegment};
+use is_same::IsSame;
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[test]
+fn check_first_difference_path() {
+    let path = DiffPath::new()
+        .prepend_field("title")
+        .prepend_index(3)
+        .prepend_field("panes")
+        .prepend_field("settings");
+    assert_eq!(path.to_string(), "settings.panes[3].title");
+    assert_eq!(
+        path.segments().cloned().collect::<Vec<_>>(),
+        vec![
+            PathSegment::Field("settings"),
+            PathSegment::Field("panes"),
+            PathSegment::Index(3),
+            PathSegment::Field("title"),
+        ]
+    );
+    assert_eq!(DiffPath::new().prepend_key("foo").to_string(), "[\"foo\"]");
+    assert_eq!(DiffPath::new().to_string(), "(root)");
+    assert_eq!(DiffPath::new().prepend_index(0).to_string(), "[0]");
+}
+
+#[test]
+fn check_first_difference() {
+    assert_eq!(1u8.first_difference(&1), None);
+    assert_eq!(1u8.first_difference(&2), Some(DiffPath::new()));
+    assert_eq!(f32::NAN.first_difference(&f32::NAN), None);
+
+    let left = vec![vec![1, 2], vec![3, 4]];
+    assert_eq!(left.first_difference(&left.clone()), None);
+    let path = left
+        .first_difference(&vec![vec![1, 2], vec![3, 5]])
+        .unwrap();
+    assert_eq!(path.to_string(), "[1][1]");
+    let path = left.first_difference(&vec![vec![1, 2]]).unwrap();
+    assert_eq!(path.to_string(), "[1]");
+
+    let mut map = BTreeMap::new();
+    map.insert("a", Some(1));
+    map.insert("b", None);
+    assert_eq!(map.first_difference(&map.clone()), None);
+    let mut other = map.clone();
+    other.insert("b", Some(2));
+    assert_eq!(map.first_difference(&other).unwrap().to_string(), "[\"b\"]");
+    other.remove("a");
+    assert_eq!(map.first_difference(&other).unwrap().to_string(), "[\"a\"]");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_first_difference_hash_map() {
+    let map = HashMap::from([("a", Some(1)), ("b", None)]);
+    let mut other = map.clone();
+    assert_eq!(map.first_difference(&other), None);
+    other.insert("c", None);
+    assert_eq!(map.first_difference(&other).unwrap().to_string(), "[\"c\"]");
+}
+
+#[test]
+fn check_first_difference_tuple() {
+    assert_eq!((1, 'a').first_difference(&(1, 'a')), None);
+    let path = (1, vec!['a']).first_difference(&(1, vec!['b'])).unwrap();
+    assert_eq!(path.to_string(), "1[0]");
+
+    let left = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    let mut right = left;
+    right.15 = 0;
+    assert_eq!(left.first_difference(&right).unwrap().to_string(), "15");
+}
+
+// `Rc`s with equal contents are equal, but only the same if they share
+// an allocation, so the keys here have to be matched up with IsSame.
+#[test]
+fn check_first_difference_keys_use_is_same() {
+    use std::rc::Rc;
+
+    let key: Rc<str> = Rc::from("a");
+    let map = BTreeMap::from([(key.clone(), 1), (Rc::from("b"), 2)]);
+    assert_eq!(map.first_difference(&map.clone()), None);
+    let mut other = map.clone();
+    other.remove(&key);
+    other.insert(Rc::from("a"), 1);
+    assert!(map.is_not_same(&other));
+    assert_eq!(map.first_difference(&other).unwrap().to_string(), "[\"a\"]");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_first_difference_hash_map_keys_use_is_same() {
+    use std::rc::Rc;
+
+    let key: Rc<str> = Rc::from("a");
+    let map = HashMap::from([(key.clone(), 1)]);
+    assert_eq!(map.first_difference(&map.clone()), None);
+    let other = HashMap::from([(Rc::<str>::from("a"), 1)]);
+    assert!(map.is_not_same(&other));
+    assert_eq!(map.first_difference(&other).unwrap().to_string(), "[\"a\"]");
+    assert_eq!(other.first_difference(&map).unwrap().to_string(), "[\"a\"]");
+}
@@ -0,0 +1,76 @@
+use is_same::graph::{graph_is_same, GraphComparison, GraphNode};
+use is_same::IsSame;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+struct Node {
+    label: String,
+    parent: Weak<RefCell<Node>>,
+    children: Vec<Rc<RefCell<Node>>>,
+}
+
+impl GraphNode for Node {
+    fn is_same_node(&self, other: &Node, graph: &mut GraphComparison) -> bool {
+        self.label.is_same(&other.label)
+            && graph.compare_weak(&self.parent, &other.parent)
+            && graph.compare_slice(&self.children, &other.children)
+    }
+}
+
+fn node(label: &str) -> Rc<RefCell<Node>> {
+    Rc::new(RefCell::new(Node {
+        label: label.to_owned(),
+        parent: Weak::new(),
+        children: Vec::new(),
+    }))
+}
+
+fn add_child(parent: &Rc<RefCell<Node>>, child: Rc<RefCell<Node>>) {
+    child.borrow_mut().parent = Rc::downgrade(parent);
+    parent.borrow_mut().children.push(child);
+}
+
+fn tree(labels: &[&str]) -> Rc<RefCell<Node>> {
+    let root = node("root");
+    for label in labels {
+        add_child(&root, node(label));
+    }
+    root
+}
+
+#[test]
+fn trees_with_parent_links() {
+    assert!(graph_is_same(&tree(&["a", "b"]), &tree(&["a", "b"])));
+    assert!(!graph_is_same(&tree(&["a", "b"]), &tree(&["a", "c"])));
+    assert!(!graph_is_same(&tree(&["a"]), &tree(&["a", "b"])));
+
+    let shared = tree(&["a"]);
+    assert!(graph_is_same(&shared, &shared.clone()));
+
+    // A child whose parent link points somewhere else isn't the same.
+    let left = tree(&["a"]);
+    let right = tree(&["a"]);
+    right.borrow().children[0].borrow_mut().parent = Rc::downgrade(&node("root"));
+    assert!(!graph_is_same(&left, &right));
+}
+
+#[test]
+fn cycles() {
+    // Rings of the same labels are the same no matter how many times the
+    // pattern repeats, since they unroll to the same infinite sequence.
+    let ring = |labels: &[&str]| {
+        let nodes = labels.iter().map(|label| node(label)).collect::<Vec<_>>();
+        for (index, node) in nodes.iter().enumerate() {
+            let next = nodes[(index + 1) % nodes.len()].clone();
+            node.borrow_mut().children.push(next);
+        }
+        nodes[0].clone()
+    };
+    assert!(graph_is_same(&ring(&["a", "b"]), &ring(&["a", "b"])));
+    assert!(graph_is_same(
+        &ring(&["a", "b"]),
+        &ring(&["a", "b", "a", "b"])
+    ));
+    assert!(!graph_is_same(&ring(&["a", "b"]), &ring(&["a", "b", "c"])));
+    assert!(!graph_is_same(&ring(&["a"]), &ring(&["b"])));
+}
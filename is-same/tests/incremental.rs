@@ -0,0 +1,50 @@
+use is_same::incremental::{Comparison, Status};
+use std::collections::BTreeMap;
+
+#[test]
+fn chunks() {
+    let old = (0..10).map(|i| i as f32).collect::<Vec<_>>();
+    let mut new = old.clone();
+    new[7] = f32::NAN;
+
+    let mut comparison = Comparison::new(&old, &new);
+    assert_eq!(comparison.status(), Status::Pending);
+    assert_eq!(comparison.advance(5), Status::Pending);
+    assert_eq!(comparison.advance(2), Status::Pending);
+    assert_eq!(comparison.advance(1), Status::NotSame);
+    assert_eq!(comparison.advance(100), Status::NotSame);
+
+    let mut comparison = Comparison::new(&old, &old);
+    assert_eq!(comparison.advance(10), Status::Pending);
+    assert_eq!(comparison.advance(1), Status::Same);
+    assert_eq!(comparison.advance(0), Status::Same);
+}
+
+#[test]
+fn lengths() {
+    let mut comparison = Comparison::new(&[1, 2, 3], &[1, 2]);
+    assert_eq!(comparison.status(), Status::NotSame);
+    assert_eq!(comparison.advance(0), Status::NotSame);
+
+    // Without exact size hints, the mismatch shows up at the end.
+    let evens = (0..6).filter(|i| i % 2 == 0);
+    let mut comparison = Comparison::new(evens, [0, 2]);
+    assert_eq!(comparison.advance(2), Status::Pending);
+    assert_eq!(comparison.finish(), Status::NotSame);
+
+    let mut comparison = Comparison::new(Vec::<u8>::new(), Vec::<u8>::new());
+    assert_eq!(comparison.advance(1), Status::Same);
+}
+
+#[test]
+fn btree_map() {
+    let old = (0..100)
+        .map(|i| (i, i.to_string()))
+        .collect::<BTreeMap<_, _>>();
+    let mut new = old.clone();
+    assert_eq!(Comparison::new(&old, &new).finish(), Status::Same);
+    new.insert(50, "changed".to_owned());
+    let mut comparison = Comparison::new(&old, &new);
+    assert_eq!(comparison.advance(50), Status::Pending);
+    assert_eq!(comparison.advance(1), Status::NotSame);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "std")]
+
+use is_same::interner::Interner;
+use is_same::IsSame;
+use std::collections::HashSet;
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+struct Style {
+    size: u32,
+    font: &'static str,
+}
+
+#[test]
+fn intern_values() {
+    let mut interner = Interner::new();
+    let a = interner.intern(Style {
+        size: 12,
+        font: "serif",
+    });
+    let b = interner.intern(Style {
+        size: 12,
+        font: "serif",
+    });
+    let c = interner.intern(Style {
+        size: 14,
+        font: "serif",
+    });
+    assert!(a.is_same(&b));
+    assert!(a.is_not_same(&c));
+    assert_eq!(a, b);
+    assert_eq!(a.size, 12);
+    assert_eq!(interner.len(), 2);
+    let lookup = interner
+        .get(&Style {
+            size: 14,
+            font: "serif",
+        })
+        .unwrap();
+    assert!(lookup.is_same(&c));
+    assert!(interner
+        .get(&Style {
+            size: 1,
+            font: "serif"
+        })
+        .is_none());
+
+    let set = vec![a.clone(), b, c.clone()]
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn intern_str() {
+    let mut interner = Interner::<str>::new();
+    let owned = "bold".to_owned();
+    let a = interner.intern_from("bold");
+    let b = interner.intern_from(&owned[..]);
+    assert!(a.is_same(&b));
+    assert_eq!(a.to_string(), "bold");
+    assert_eq!(format!("{:?}", a), "\"bold\"");
+
+    // Handles from a different interner are never the same.
+    let mut other = Interner::<str>::new();
+    assert!(a.is_not_same(&other.intern_from("bold")));
+}
+
+#[test]
+fn sweep() {
+    let mut interner = Interner::<str>::new();
+    let kept = interner.intern_from("kept");
+    interner.intern_from("dropped");
+    assert_eq!(interner.sweep(), 1);
+    assert_eq!(interner.len(), 1);
+    assert!(interner.intern_from("kept").is_same(&kept));
+    drop(kept);
+    assert_eq!(interner.sweep(), 1);
+    assert!(interner.is_empty());
+}
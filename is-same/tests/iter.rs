@@ -0,0 +1,32 @@
+use is_same::iter::{iter_is_same, iter_is_same_by};
+use std::cell::Cell;
+
+#[test]
+fn compare() {
+    assert!(iter_is_same(vec![1.0, f64::NAN], [1.0, f64::NAN]));
+    assert!(!iter_is_same(vec![0.0], vec![-0.0]));
+    assert!(!iter_is_same(1..4, 1..3));
+    assert!(!iter_is_same(1..3, 1..4));
+    assert!(iter_is_same(
+        std::iter::empty::<u8>(),
+        std::iter::empty::<u8>()
+    ));
+    assert!(iter_is_same_by(
+        "abc".chars(),
+        "ABC".chars(),
+        |left, right| { left.eq_ignore_ascii_case(&right) }
+    ));
+}
+
+#[test]
+fn size_hint_shortcut() {
+    let visited = Cell::new(0);
+    let long = (0..100).inspect(|_| visited.set(visited.get() + 1));
+    assert!(!iter_is_same(long, 0..99));
+    assert_eq!(visited.get(), 0);
+
+    // Without exact size hints the elements still have to be visited.
+    let evens = (0..10).filter(|i| i % 2 == 0);
+    assert!(iter_is_same(evens.clone(), [0, 2, 4, 6, 8]));
+    assert!(!iter_is_same(evens, [0, 2, 4, 6]));
+}
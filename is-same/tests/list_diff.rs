@@ -0,0 +1,98 @@
+use is_same::list_diff::{list_diff, ListEdit};
+
+fn keyed(keys: &str) -> Vec<(char, f32)> {
+    keys.chars().map(|key| (key, 0.0)).collect()
+}
+
+#[test]
+fn check_list_diff() {
+    let key = |(key, _): &(char, f32)| *key;
+
+    let old = keyed("abcd");
+    assert_eq!(list_diff(&old, &old, key), vec![]);
+
+    let mut new = old.clone();
+    new[1].1 = f32::NAN;
+    assert_eq!(
+        list_diff(&old, &new, key),
+        vec![ListEdit::Updated {
+            key: 'b',
+            old_index: 1,
+            new_index: 1,
+        }]
+    );
+
+    assert_eq!(
+        list_diff(&old, &keyed("xbd"), key),
+        vec![
+            ListEdit::Removed {
+                key: 'a',
+                old_index: 0,
+            },
+            ListEdit::Removed {
+                key: 'c',
+                old_index: 2,
+            },
+            ListEdit::Inserted {
+                key: 'x',
+                new_index: 0,
+            },
+        ]
+    );
+
+    // Only `d` needs to move to get from abcd to dabc.
+    assert_eq!(
+        list_diff(&old, &keyed("dabc"), key),
+        vec![ListEdit::Moved {
+            key: 'd',
+            old_index: 3,
+            new_index: 0,
+        }]
+    );
+
+    let mut new = keyed("bcda");
+    new[3].1 = 1.0;
+    assert_eq!(
+        list_diff(&old, &new, key),
+        vec![
+            ListEdit::Moved {
+                key: 'a',
+                old_index: 0,
+                new_index: 3,
+            },
+            ListEdit::Updated {
+                key: 'a',
+                old_index: 0,
+                new_index: 3,
+            },
+        ]
+    );
+
+    // Duplicate keys after the first are inserted and removed.
+    assert_eq!(
+        list_diff(&keyed("aa"), &keyed("a"), key),
+        vec![ListEdit::Removed {
+            key: 'a',
+            old_index: 1,
+        }]
+    );
+    assert_eq!(
+        list_diff(&keyed("a"), &keyed("aa"), key),
+        vec![ListEdit::Inserted {
+            key: 'a',
+            new_index: 1,
+        }]
+    );
+}
+
+#[test]
+fn check_list_diff_reverse() {
+    let old = (0..6).collect::<Vec<u32>>();
+    let new = old.iter().rev().copied().collect::<Vec<u32>>();
+    let edits = list_diff(&old, &new, |value| *value);
+    // Everything but one element has to move.
+    assert_eq!(edits.len(), 5);
+    assert!(edits
+        .iter()
+        .all(|edit| matches!(edit, ListEdit::Moved { .. })));
+}
@@ -0,0 +1,58 @@
+use is_same::map_delta::map_delta;
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[test]
+fn check_map_delta() {
+    let old = BTreeMap::from([("a", f32::NAN), ("b", 0.0), ("c", 1.0)]);
+    assert!(map_delta(&old, &old.clone()).is_empty());
+    let new = BTreeMap::from([("a", f32::NAN), ("b", -0.0), ("d", 2.0)]);
+    let delta = map_delta(&old, &new);
+    assert_eq!(delta.added, vec![(&"d", &2.0)]);
+    assert_eq!(delta.removed, vec![(&"c", &1.0)]);
+    assert_eq!(delta.changed.len(), 1);
+    assert_eq!(delta.changed[0].0, &"b");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_map_delta_hash_map() {
+    let old = HashMap::from([("a", f32::NAN), ("b", 0.0), ("c", 1.0)]);
+    assert!(map_delta(&old, &old.clone()).is_empty());
+    let new = HashMap::from([("a", f32::NAN), ("b", -0.0), ("d", 2.0)]);
+    let delta = map_delta(&old, &new);
+    assert_eq!(delta.added, vec![(&"d", &2.0)]);
+    assert_eq!(delta.removed, vec![(&"c", &1.0)]);
+    assert_eq!(delta.changed.len(), 1);
+    assert!(!delta.is_empty());
+}
+
+// `Rc`s with equal contents are equal, but only the same if they share
+// an allocation, so the keys here have to be matched up with IsSame.
+#[test]
+fn check_map_delta_keys_use_is_same() {
+    use std::rc::Rc;
+
+    let key: Rc<str> = Rc::from("a");
+    let other_key: Rc<str> = Rc::from("a");
+    let old = BTreeMap::from([(key.clone(), 1)]);
+    assert!(map_delta(&old, &old.clone()).is_empty());
+    let new = BTreeMap::from([(other_key.clone(), 1)]);
+    let delta = map_delta(&old, &new);
+    assert!(Rc::ptr_eq(delta.removed[0].0, &key));
+    assert!(Rc::ptr_eq(delta.added[0].0, &other_key));
+    assert!(delta.changed.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_map_delta_hash_map_keys_use_is_same() {
+    use std::rc::Rc;
+
+    let old = HashMap::from([(Rc::<str>::from("a"), 1)]);
+    let new = HashMap::from([(Rc::<str>::from("a"), 1)]);
+    assert!(map_delta(&old, &old.clone()).is_empty());
+    let delta = map_delta(&old, &new);
+    assert_eq!((delta.removed.len(), delta.added.len()), (1, 1));
+}
@@ -0,0 +1,74 @@
+use is_same::memo::{memo, memo_with_status};
+
+#[test]
+fn check_memo() {
+    let mut calls = 0;
+    let mut compute = |input: &Vec<f32>| {
+        calls += 1;
+        input.len()
+    };
+    let (input, output) = memo(&vec![1.0], vec![1.0], 5, &mut compute);
+    assert_eq!((input, output), (vec![1.0], 5));
+    let (input, output) = memo(&vec![1.0], vec![1.0, f32::NAN], 5, &mut compute);
+    assert_eq!(output, 2);
+    assert_eq!(input.len(), 2);
+    assert_eq!(calls, 1);
+
+    let (_, output, recomputed) = memo_with_status(&f32::NAN, f32::NAN, 1, |_| 2);
+    assert_eq!((output, recomputed), (1, false));
+    let (_, output, recomputed) = memo_with_status(&0.0, -0.0, 1, |_| 2);
+    assert_eq!((output, recomputed), (2, true));
+}
+
+#[test]
+fn check_memo_struct() {
+    use is_same::memo::Memo;
+
+    let mut memo = Memo::new();
+    let mut calls = 0;
+    assert_eq!(memo.output(), None);
+    for input in &[1.0, 1.0, f64::NAN, f64::NAN, -0.0, 0.0] {
+        memo.get_or_compute(*input, |input| {
+            calls += 1;
+            input.to_bits()
+        });
+    }
+    assert_eq!(calls, 4);
+    assert_eq!(memo.output(), Some(&0.0f64.to_bits()));
+    assert_eq!(memo.input(), Some(&0.0));
+
+    memo.clear();
+    assert_eq!(memo.input(), None);
+    assert_eq!(*memo.get_or_compute(0.0, |_| 5), 5);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_memo_map() {
+    use is_same::memo::MemoMap;
+
+    let mut cache = MemoMap::new();
+    let mut calls = 0;
+    let mut update = |cache: &mut MemoMap<u32, Vec<f32>, usize>, key, input: Vec<f32>| {
+        *cache.get_or_compute(key, input, |input| {
+            calls += 1;
+            input.len()
+        })
+    };
+    assert_eq!(update(&mut cache, 1, vec![f32::NAN]), 1);
+    assert_eq!(update(&mut cache, 2, vec![1.0, 2.0]), 2);
+    assert_eq!(update(&mut cache, 1, vec![f32::NAN]), 1);
+    assert_eq!(update(&mut cache, 2, vec![1.0]), 1);
+    assert_eq!(cache.get(&2), Some(&1));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.sweep(), 0);
+
+    // Only key 1 is used before the next sweep.
+    assert_eq!(update(&mut cache, 1, vec![f32::NAN]), 1);
+    assert_eq!(cache.sweep(), 1);
+    assert_eq!(cache.get(&2), None);
+    let (input, output) = cache.remove(&1).unwrap();
+    assert_eq!((input.len(), output), (1, 1));
+    assert!(cache.is_empty());
+    assert_eq!(calls, 3);
+}
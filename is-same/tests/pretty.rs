@@ -0,0 +1,45 @@
+#![cfg(feature = "pretty")]
+
+use is_same::{assert_same, pretty};
+
+#[derive(Debug)]
+struct Config {
+    name: &'static str,
+    scale: f32,
+}
+
+#[test]
+fn plain() {
+    let (left, right) = (vec!["a", "b"], vec!["a", "b", "c"]);
+    let diff = pretty::diff(&left, &right).color(false).to_string();
+    assert_eq!(diff, " [\n     \"a\",\n     \"b\",\n+    \"c\",\n ]\n");
+
+    let diff = pretty::diff(&1, &1).color(false).to_string();
+    assert_eq!(diff, " 1\n");
+}
+
+#[test]
+fn highlights_changed_parts() {
+    let left = Config {
+        name: "foo",
+        scale: 1.0,
+    };
+    let right = Config {
+        name: "foo",
+        scale: 1.5,
+    };
+    assert!(left.name == right.name && left.scale != right.scale);
+    let diff = pretty::diff(&left, &right).color(true).to_string();
+    let lines = diff.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], " Config {");
+    assert_eq!(lines[1], "     name: \"foo\",");
+    assert_eq!(lines[2], "\x1b[31m-    scale: 1.\x1b[7m0\x1b[27m,\x1b[0m");
+    assert_eq!(lines[3], "\x1b[32m+    scale: 1.\x1b[7m5\x1b[27m,\x1b[0m");
+    assert_eq!(lines[4], " }");
+}
+
+#[test]
+#[should_panic(expected = "\n  diff (- left, + right):\n ")]
+fn assert_same_includes_diff() {
+    assert_same!(vec![1.0, 2.0], vec![1.0, 3.0]);
+}
@@ -0,0 +1,64 @@
+use is_same::same_key::SameKey;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::sync::Arc;
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn floats() {
+    let mut set = HashSet::new();
+    assert!(set.insert(SameKey(f64::NAN)));
+    assert!(!set.insert(SameKey(f64::NAN)));
+    assert!(set.insert(SameKey(0.0)));
+    assert!(set.insert(SameKey(-0.0)));
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn pointers() {
+    let shared = Rc::new(vec![1.0f32; 1000]);
+    let copy = Rc::new(vec![1.0f32; 1000]);
+    let mut map = HashMap::new();
+    map.insert(SameKey(shared.clone()), "shared");
+    assert_eq!(map.get(&SameKey(shared.clone())), Some(&"shared"));
+    assert_eq!(map.get(&SameKey(copy)), None);
+
+    let arc: Arc<str> = Arc::from("a");
+    assert_eq!(hash(&SameKey(arc.clone())), hash(&SameKey(arc)));
+}
+
+#[test]
+fn consistent_with_is_same() {
+    let keys = (Some("a".to_owned()), vec![(1u8, 'x')], [0.5f64; 3]);
+    assert!(SameKey(keys.clone()) == SameKey(keys.clone()));
+    assert_eq!(hash(&SameKey(keys.clone())), hash(&SameKey(keys.clone())));
+    assert_eq!(*SameKey(keys.clone()), keys);
+
+    let tuple = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15);
+    assert_eq!(hash(&SameKey(tuple)), hash(&SameKey(tuple)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_map_consistent_with_is_same() {
+    use is_same::same_key::SameHash;
+
+    let mut left = HashMap::new();
+    let mut right = HashMap::new();
+    for i in 0..20 {
+        left.insert(i, f32::NAN);
+        right.insert(19 - i, f32::NAN);
+    }
+    assert_eq!(hash(&SameKey(left.clone())), hash(&SameKey(right)));
+
+    let mut hasher = DefaultHasher::new();
+    left.same_hash(&mut hasher);
+    assert_eq!(hasher.finish(), hash(&SameKey(left)));
+}
@@ -0,0 +1,128 @@
+use is_same::sequence_diff::{sequence_diff, Edit};
+
+/// Checks that the edits cover both sequences in order, that equal runs
+/// really are equal, and returns the number of changed elements.
+fn apply(old: &[u8], new: &[u8], edits: &[Edit]) -> usize {
+    let (mut old_pos, mut new_pos, mut changed) = (0, 0, 0);
+    let mut previous = None;
+    for edit in edits {
+        let kind = std::mem::discriminant(edit);
+        assert_ne!(previous, Some(kind), "adjacent edits weren't merged");
+        previous = Some(kind);
+        match *edit {
+            Edit::Equal {
+                old_index,
+                new_index,
+                len,
+            } => {
+                assert_eq!((old_index, new_index), (old_pos, new_pos));
+                assert_eq!(old[old_index..][..len], new[new_index..][..len]);
+                old_pos += len;
+                new_pos += len;
+            }
+            Edit::Delete { old_index, len } => {
+                assert_eq!(old_index, old_pos);
+                old_pos += len;
+                changed += len;
+            }
+            Edit::Insert { new_index, len } => {
+                assert_eq!(new_index, new_pos);
+                new_pos += len;
+                changed += len;
+            }
+        }
+    }
+    assert_eq!((old_pos, new_pos), (old.len(), new.len()));
+    changed
+}
+
+fn lcs_len(old: &[u8], new: &[u8]) -> usize {
+    let mut table = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in 0..old.len() {
+        for j in 0..new.len() {
+            table[i + 1][j + 1] = if old[i] == new[j] {
+                table[i][j] + 1
+            } else {
+                table[i][j + 1].max(table[i + 1][j])
+            };
+        }
+    }
+    table[old.len()][new.len()]
+}
+
+#[test]
+fn check_sequence_diff() {
+    assert_eq!(sequence_diff::<u8, u8>(&[], &[]), vec![]);
+    assert_eq!(
+        sequence_diff(&[1, 2], &[1, 2]),
+        vec![Edit::Equal {
+            old_index: 0,
+            new_index: 0,
+            len: 2
+        }]
+    );
+    assert_eq!(
+        sequence_diff(&[1, 2], &[3]),
+        vec![
+            Edit::Delete {
+                old_index: 0,
+                len: 2
+            },
+            Edit::Insert {
+                new_index: 0,
+                len: 1
+            },
+        ]
+    );
+    // Compared with IsSame, so NaN matches itself.
+    assert_eq!(
+        sequence_diff(&[f32::NAN, 0.0], &[f32::NAN, -0.0]),
+        vec![
+            Edit::Equal {
+                old_index: 0,
+                new_index: 0,
+                len: 1
+            },
+            Edit::Delete {
+                old_index: 1,
+                len: 1
+            },
+            Edit::Insert {
+                new_index: 1,
+                len: 1
+            },
+        ]
+    );
+}
+
+#[test]
+fn check_sequence_diff_minimal() {
+    // A small xorshift generator, so the test is deterministic.
+    let mut seed = 0x2545_f491_u32;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed
+    };
+    for _ in 0..500 {
+        let old_len = (next() % 20) as usize;
+        let new_len = (next() % 20) as usize;
+        let alphabet = next() % 4 + 1;
+        let old = (0..old_len)
+            .map(|_| (next() % alphabet) as u8)
+            .collect::<Vec<_>>();
+        let new = (0..new_len)
+            .map(|_| (next() % alphabet) as u8)
+            .collect::<Vec<_>>();
+        let edits = sequence_diff(&old, &new);
+        let changed = apply(&old, &new, &edits);
+        assert_eq!(
+            changed,
+            old.len() + new.len() - 2 * lcs_len(&old, &new),
+            "not minimal for {:?} -> {:?}",
+            old,
+            new
+        );
+    }
+}
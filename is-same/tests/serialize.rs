@@ -0,0 +1,84 @@
+#![cfg(feature = "serde")]
+
+use is_same::serialize::{same_by_serialize, BySerde};
+use is_same::IsSame;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[test]
+fn floats_by_bits() {
+    assert!(same_by_serialize(&f64::NAN, &f64::NAN));
+    assert!(!same_by_serialize(&0.0f32, &-0.0f32));
+    assert!(same_by_serialize(&[1.0, 2.0][..], &vec![1.0, 2.0]));
+}
+
+#[test]
+fn structure() {
+    assert!(same_by_serialize(&Some("a"), &Some("a".to_owned())));
+    assert!(!same_by_serialize(&Some(0u8), &None::<u8>));
+    assert!(!same_by_serialize(&vec!["ab"], &vec!["a", "b"]));
+    assert!(!same_by_serialize(&vec![1u8, 2], &vec![1u8]));
+    assert!(!same_by_serialize(&vec![1u8], &vec![1u8, 2]));
+    assert!(!same_by_serialize(&Ok::<u8, u8>(1), &Err::<u8, u8>(1)));
+    assert!(same_by_serialize(&(1u8, 'x', ()), &(1u8, 'x', ())));
+
+    let mut left = BTreeMap::new();
+    left.insert("a", vec![f32::NAN]);
+    let mut right = left.clone();
+    assert!(same_by_serialize(&left, &right));
+    right.insert("b", vec![]);
+    assert!(!same_by_serialize(&left, &right));
+}
+
+#[test]
+fn wrapper() {
+    let left = BySerde(vec![Some(1.5), None]);
+    assert!(left.is_same(&BySerde(vec![Some(1.5), None])));
+    assert!(left.is_not_same(&BySerde(vec![None, Some(1.5)])));
+    assert_eq!(left.len(), 2);
+    assert_eq!(BySerde::from(3).into_inner(), 3);
+}
+
+#[derive(Serialize)]
+struct Message {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<u32>,
+}
+
+#[derive(Serialize)]
+enum Event {
+    Changed {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        old: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        new: Option<u32>,
+    },
+}
+
+#[test]
+fn skipped_fields() {
+    let id = Message {
+        id: Some(1),
+        count: None,
+    };
+    let count = Message {
+        id: None,
+        count: Some(1),
+    };
+    assert!(same_by_serialize(&id, &id));
+    assert!(!same_by_serialize(&id, &count));
+    assert!(!BySerde(&count).is_same(&BySerde(&id)));
+
+    let old = Event::Changed {
+        old: Some(1),
+        new: None,
+    };
+    let new = Event::Changed {
+        old: None,
+        new: Some(1),
+    };
+    assert!(same_by_serialize(&old, &old));
+    assert!(!same_by_serialize(&old, &new));
+}
@@ -0,0 +1,44 @@
+use is_same::signal::Signal;
+use std::cell::RefCell;
+#[cfg(feature = "std")]
+use std::sync::mpsc;
+
+#[test]
+fn notifies_on_change() {
+    let seen = RefCell::new(Vec::new());
+    let mut signal = Signal::new(0.0f32);
+    let id = signal.subscribe(|value| seen.borrow_mut().push(*value));
+    assert!(!signal.set(0.0));
+    assert!(signal.set(-0.0));
+    assert!(signal.set(f32::NAN));
+    assert!(!signal.set(f32::NAN));
+    assert!(signal.get().is_nan());
+
+    assert!(signal.unsubscribe(id));
+    assert!(!signal.unsubscribe(id));
+    assert!(signal.set(1.0));
+    assert_eq!(signal.into_inner(), 1.0);
+
+    let seen = seen.into_inner();
+    assert_eq!(seen.len(), 2);
+    assert_eq!(seen[0].to_bits(), (-0.0f32).to_bits());
+    assert!(seen[1].is_nan());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sender() {
+    let (sender, receiver) = mpsc::channel();
+    let mut signal = Signal::new("a".to_owned());
+    signal.subscribe_sender(sender);
+    signal.set("a".to_owned());
+    signal.set("b".to_owned());
+    signal.set("b".to_owned());
+    signal.set("c".to_owned());
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), ["b", "c"]);
+
+    drop(receiver);
+    assert_eq!(signal.subscriber_count(), 1);
+    signal.set("d".to_owned());
+    assert_eq!(signal.subscriber_count(), 0);
+}
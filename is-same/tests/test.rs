@@ -1,6 +1,9 @@
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+
 use is_same::IsSame;
 
 #[test]
+#[allow(clippy::legacy_numeric_constants)]
 fn check_floats() {
     assert!((1.0f32).is_same(&1.0f32));
     assert!((0.0f32).is_same(&0.0f32));
@@ -63,6 +66,44 @@ fn check_btree_set() {
     assert!(map1.is_same(&map2));
 }
 
+#[test]
+fn check_map_keys_use_is_same() {
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::rc::Rc;
+
+    // Equal keys in different allocations aren't the same.
+    let key1: Rc<str> = "foo".into();
+    let key2: Rc<str> = "foo".into();
+
+    let btree_map = |key: &Rc<str>| BTreeMap::from([(key.clone(), 1)]);
+    assert!(btree_map(&key1).is_same(&btree_map(&key1)));
+    assert!(btree_map(&key1).is_not_same(&btree_map(&key2)));
+
+    let btree_set = |key: &Rc<str>| BTreeSet::from([key.clone()]);
+    assert!(btree_set(&key1).is_same(&btree_set(&key1)));
+    assert!(btree_set(&key1).is_not_same(&btree_set(&key2)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_hash_map_keys_use_is_same() {
+    use std::collections::{HashMap, HashSet};
+    use std::rc::Rc;
+
+    // Equal keys in different allocations aren't the same.
+    let key1: Rc<str> = "foo".into();
+    let key2: Rc<str> = "foo".into();
+
+    let hash_map = |key: &Rc<str>| HashMap::from([(key.clone(), 1)]);
+    assert!(hash_map(&key1).is_same(&hash_map(&key1)));
+    assert!(hash_map(&key1).is_not_same(&hash_map(&key2)));
+
+    let hash_set = |key: &Rc<str>| HashSet::from([key.clone()]);
+    assert!(hash_set(&key1).is_same(&hash_set(&key1)));
+    assert!(hash_set(&key1).is_not_same(&hash_set(&key2)));
+}
+
+#[cfg(feature = "std")]
 #[test]
 fn check_hash_map() {
     use std::collections::HashMap;
@@ -86,6 +127,7 @@ fn check_hash_map() {
     assert!(map1.is_not_same(&map2));
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn check_hash_set() {
     use std::collections::HashSet;
@@ -137,6 +179,25 @@ fn simple_checks() {
     assert!(rc1.is_not_same(&rc2));
 }
 
+#[test]
+fn check_shared_pointer_against_value() {
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let rc = Rc::new(vec![f32::NAN]);
+    assert!(rc.is_same(&vec![f32::NAN]));
+    assert!(rc.is_not_same(&vec![0.0]));
+    let value: &Vec<f32> = &rc;
+    assert!(rc.is_same(&value));
+
+    let arc: Arc<str> = "config".into();
+    assert!(arc.is_same("config"));
+    assert!(arc.is_same(&"config"));
+    assert!(arc.is_not_same("other"));
+    // Separately allocated pointers are still compared by address.
+    assert!(arc.is_not_same(&Arc::<str>::from("config")));
+}
+
 #[test]
 fn check_arrays() {
     let arr1 = [1, 2, 3];
@@ -159,6 +220,29 @@ fn check_slices() {
 }
 
 #[test]
+fn check_byte_slices() {
+    let blob1 = vec![7u8; 1 << 16];
+    let mut blob2 = blob1.clone();
+    assert!(blob1.is_same(&blob2));
+    assert!(u8::is_same_slice(&blob1, &blob2));
+    *blob2.last_mut().unwrap() = 8;
+    assert!(blob1.is_not_same(&blob2));
+    assert!(blob1[..].is_not_same(&blob2[..]));
+    assert!(!u8::is_same_slice(&blob1, &blob2));
+
+    // The default still compares floats by their bit patterns.
+    assert!(f32::is_same_slice(&[f32::NAN], &[f32::NAN]));
+    assert!(!f32::is_same_slice(&[0.0], &[-0.0]));
+
+    // Called directly, slices of different lengths aren't the same even
+    // when one is a prefix of the other.
+    assert!(!f32::is_same_slice(&[1.0], &[1.0, 2.0]));
+    assert!(!Option::<u8>::is_same_slice(&[Some(1)], &[Some(1), None]));
+    assert!(Option::<u8>::is_same_slice(&[Some(1)], &[Some(1)]));
+}
+
+#[test]
+#[allow(clippy::suspicious_to_owned)]
 fn check_cow() {
     use std::borrow::Cow;
 
@@ -184,6 +268,39 @@ fn check_cow() {
     assert!(cow1.is_same(&cow2));
 }
 
+#[test]
+fn check_cow_borrowed_fast_path() {
+    use std::borrow::Cow;
+
+    // Never the same, so comparisons only succeed through the pointer
+    // check. It isn't zero sized, since those skip the check.
+    #[derive(Clone)]
+    struct Never(u8);
+
+    impl IsSame for Never {
+        fn is_same(&self, other: &Self) -> bool {
+            let _ = (self.0, other.0);
+            false
+        }
+    }
+
+    let data = vec![Never(1), Never(2)];
+    let cow1: Cow<'_, [Never]> = Cow::Borrowed(&data);
+    let cow2: Cow<'_, [Never]> = Cow::Borrowed(&data[..]);
+    assert!(cow1.is_same(&cow2));
+    let cow2: Cow<'_, [Never]> = Cow::Borrowed(&data[..1]);
+    assert!(cow1.is_not_same(&cow2));
+    let cow2: Cow<'_, [Never]> = Cow::Owned(data.clone());
+    assert!(cow1.is_not_same(&cow2));
+
+    // The lengths have to match as well as the addresses.
+    let text = "foobar";
+    let cow1: Cow<'_, str> = Cow::Borrowed(&text[..3]);
+    let cow2: Cow<'_, str> = Cow::Borrowed(text);
+    assert!(cow1.is_not_same(&cow2));
+    assert!(cow1.is_same(&Cow::Borrowed(&text[..3])));
+}
+
 #[test]
 fn check_tuples() {
     let t1 = (1, 2, "baz");
@@ -191,6 +308,19 @@ fn check_tuples() {
     assert!(t1.is_same(&t2));
     let t2 = (1, 3, "baz");
     assert!(t1.is_not_same(&t2));
+
+    // Zero sized values can share an address without being the same
+    // value, so zero sized tuples skip the pointer check.
+    struct Never;
+
+    impl IsSame for Never {
+        fn is_same(&self, _other: &Self) -> bool {
+            false
+        }
+    }
+
+    let never = (Never, ());
+    assert!(never.is_not_same(&never));
 }
 
 #[test]
@@ -204,7 +334,9 @@ fn check_type_id() {
     assert!(t1.is_not_same(&t2));
 }
 
+#[cfg(feature = "std")]
 #[test]
+#[allow(clippy::explicit_auto_deref)]
 fn check_path() {
     use std::path::{Path, PathBuf};
 
@@ -232,3 +364,1391 @@ fn check_unsized() {
     let arr: &[u8] = &[1, 2, 3];
     assert!(IsSame::<[u8]>::is_same(arr, arr));
 }
+
+#[cfg(feature = "std")]
+#[test]
+fn check_locks() {
+    use std::sync::{Arc, Mutex, RwLock};
+
+    let mutex1 = Mutex::new(vec![1, 2, 3]);
+    let mutex2 = Mutex::new(vec![1, 2, 3]);
+    assert!(mutex1.is_same(&mutex1));
+    assert!(mutex1.is_same(&mutex2));
+    assert!(mutex2.is_same(&mutex1));
+    mutex2.lock().unwrap().push(4);
+    assert!(mutex1.is_not_same(&mutex2));
+
+    let lock1 = RwLock::new("foo");
+    let lock2 = RwLock::new("foo");
+    assert!(lock1.is_same(&lock1));
+    assert!(lock1.is_same(&lock2));
+    *lock2.write().unwrap() = "bar";
+    assert!(lock1.is_not_same(&lock2));
+
+    // Poisoned locks still compare their contents.
+    let poisoned = Arc::new(Mutex::new(1));
+    let clone = poisoned.clone();
+    let _ = std::thread::spawn(move || {
+        let _guard = clone.lock().unwrap();
+        panic!("poison the mutex");
+    })
+    .join();
+    assert!(poisoned.is_poisoned());
+    assert!(poisoned.is_same(&poisoned));
+    assert!((*poisoned).is_same(&Mutex::new(1)));
+    assert!((*poisoned).is_not_same(&Mutex::new(2)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_time() {
+    use std::time::{Duration, Instant, SystemTime};
+
+    let dur1 = Duration::from_millis(1500);
+    let dur2 = Duration::from_secs(1) + Duration::from_millis(500);
+    assert!(dur1.is_same(&dur2));
+    assert!(dur1.is_not_same(&Duration::from_secs(1)));
+
+    let instant1 = Instant::now();
+    let instant2 = instant1 + Duration::from_secs(1);
+    assert!(instant1.is_same(&instant1));
+    assert!(instant1.is_not_same(&instant2));
+
+    let time1 = SystemTime::UNIX_EPOCH;
+    let time2 = time1 + Duration::from_secs(60);
+    assert!(time1.is_same(&SystemTime::UNIX_EPOCH));
+    assert!(time1.is_not_same(&time2));
+}
+
+#[test]
+fn check_net() {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+    let v4 = Ipv4Addr::new(127, 0, 0, 1);
+    assert!(v4.is_same(&Ipv4Addr::LOCALHOST));
+    assert!(v4.is_not_same(&Ipv4Addr::UNSPECIFIED));
+    let v6 = Ipv6Addr::LOCALHOST;
+    assert!(v6.is_same(&Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)));
+    assert!(v6.is_not_same(&Ipv6Addr::UNSPECIFIED));
+
+    let ip1 = IpAddr::V4(v4);
+    let ip2 = IpAddr::V6(v6);
+    assert!(ip1.is_same(&IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    assert!(ip1.is_not_same(&ip2));
+
+    let sock1 = SocketAddrV4::new(v4, 8080);
+    assert!(sock1.is_same(&SocketAddrV4::new(v4, 8080)));
+    assert!(sock1.is_not_same(&SocketAddrV4::new(v4, 8081)));
+    let sock2 = SocketAddrV6::new(v6, 8080, 0, 0);
+    assert!(sock2.is_same(&SocketAddrV6::new(v6, 8080, 0, 0)));
+    assert!(sock2.is_not_same(&SocketAddrV6::new(v6, 8080, 1, 0)));
+    assert!(SocketAddr::V4(sock1).is_same(&SocketAddr::V4(sock1)));
+    assert!(SocketAddr::V4(sock1).is_not_same(&SocketAddr::V6(sock2)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_os_str() {
+    use std::ffi::{OsStr, OsString};
+
+    let string1 = OsString::from("foo");
+    let string2 = OsString::from("bar");
+    assert!(string1.is_same(&string1));
+    assert!(string1.is_not_same(&string2));
+    let str1: &OsStr = OsStr::new("foo");
+    assert!(string1.is_same(str1));
+    assert!(str1.is_same(&string1));
+    assert!(str1.is_not_same(&string2));
+    assert!(str1.is_same(str1));
+}
+
+#[test]
+fn check_c_str() {
+    use std::ffi::{CStr, CString};
+
+    let string1 = CString::new("foo").unwrap();
+    let string2 = CString::new("bar").unwrap();
+    assert!(string1.is_same(&string1));
+    assert!(string1.is_not_same(&string2));
+    let str1: &CStr = CStr::from_bytes_with_nul(b"foo\0").unwrap();
+    assert!(string1.is_same(str1));
+    assert!(str1.is_same(&string1));
+    assert!(str1.is_not_same(&string2));
+    assert!(str1.is_same(str1));
+}
+
+#[test]
+fn check_non_zero() {
+    use std::num::{NonZeroI8, NonZeroU32, NonZeroUsize};
+
+    let id1 = NonZeroU32::new(5).unwrap();
+    let id2 = NonZeroU32::new(6).unwrap();
+    assert!(id1.is_same(&NonZeroU32::new(5).unwrap()));
+    assert!(id1.is_not_same(&id2));
+
+    let neg = NonZeroI8::new(-1).unwrap();
+    assert!(neg.is_same(&NonZeroI8::new(-1).unwrap()));
+    assert!(neg.is_not_same(&NonZeroI8::new(1).unwrap()));
+
+    let handles = [NonZeroUsize::new(1), NonZeroUsize::new(2)];
+    let handles: Vec<NonZeroUsize> = handles.iter().flatten().copied().collect();
+    assert!(handles.is_same(&handles.clone()));
+}
+
+#[test]
+fn check_adapters() {
+    use std::cmp::Reverse;
+    use std::num::{Saturating, Wrapping};
+
+    let wrap1 = Wrapping(250u8) + Wrapping(10);
+    assert!(wrap1.is_same(&Wrapping(4)));
+    assert!(wrap1.is_not_same(&Wrapping(5)));
+
+    let sat1 = Saturating(250u8) + Saturating(10);
+    assert!(sat1.is_same(&Saturating(255)));
+    assert!(sat1.is_not_same(&Saturating(4)));
+
+    let rev1 = Reverse(f32::NAN);
+    assert!(rev1.is_same(&Reverse(f32::NAN)));
+    assert!(Reverse(0.0f32).is_not_same(&Reverse(-0.0f32)));
+}
+
+#[test]
+fn check_atomics() {
+    use std::sync::atomic::{AtomicBool, AtomicI32, AtomicPtr, AtomicU64, Ordering};
+
+    let counter1 = AtomicU64::new(5);
+    let counter2 = AtomicU64::new(5);
+    assert!(counter1.is_same(&counter2));
+    counter2.fetch_add(1, Ordering::Relaxed);
+    assert!(counter1.is_not_same(&counter2));
+
+    let flag1 = AtomicBool::new(true);
+    assert!(flag1.is_same(&AtomicBool::new(true)));
+    assert!(flag1.is_not_same(&AtomicBool::new(false)));
+
+    assert!(AtomicI32::new(-3).is_same(&AtomicI32::new(-3)));
+
+    let mut value1 = 1;
+    let mut value2 = 1;
+    let ptr1 = AtomicPtr::new(&mut value1 as *mut i32);
+    let ptr2 = AtomicPtr::new(&mut value1 as *mut i32);
+    assert!(ptr1.is_same(&ptr2));
+    ptr2.store(&mut value2, Ordering::Relaxed);
+    assert!(ptr1.is_not_same(&ptr2));
+}
+
+#[test]
+fn check_ranges() {
+    use std::ops::Bound;
+
+    assert!((1..4).is_same(&(1..4)));
+    assert!((1..4).is_not_same(&(1..5)));
+    assert!((1..4).is_not_same(&(0..4)));
+    assert!((1..=4).is_same(&(1..=4)));
+    assert!((1..=4).is_not_same(&(1..=5)));
+    assert!((1..).is_same(&(1..)));
+    assert!((1..).is_not_same(&(2..)));
+    assert!((..4).is_same(&(..4)));
+    assert!((..4).is_not_same(&(..5)));
+    assert!((..=4).is_same(&(..=4)));
+    assert!((..=4).is_not_same(&(..=5)));
+    assert!((..).is_same(&(..)));
+    assert!((0.0..1.0).is_not_same(&(-0.0..1.0)));
+
+    assert!(Bound::Included(1).is_same(&Bound::Included(1)));
+    assert!(Bound::Included(1).is_not_same(&Bound::Included(2)));
+    assert!(Bound::Included(1).is_not_same(&Bound::Excluded(1)));
+    assert!(Bound::Excluded(1).is_same(&Bound::Excluded(1)));
+    assert!(Bound::<u8>::Unbounded.is_same(&Bound::Unbounded));
+    assert!(Bound::Unbounded.is_not_same(&Bound::Excluded(1)));
+}
+
+#[test]
+fn check_control_flow() {
+    use std::ops::ControlFlow;
+    use std::task::Poll;
+
+    let flow1: ControlFlow<&str, u32> = ControlFlow::Continue(1);
+    assert!(flow1.is_same(&ControlFlow::Continue(1)));
+    assert!(flow1.is_not_same(&ControlFlow::Continue(2)));
+    assert!(flow1.is_not_same(&ControlFlow::Break("done")));
+    let flow2: ControlFlow<&str, u32> = ControlFlow::Break("done");
+    assert!(flow2.is_same(&ControlFlow::Break("done")));
+    assert!(flow2.is_not_same(&ControlFlow::Break("stop")));
+
+    let poll1 = Poll::Ready(f64::NAN);
+    assert!(poll1.is_same(&Poll::Ready(f64::NAN)));
+    assert!(poll1.is_not_same(&Poll::Ready(1.0)));
+    assert!(poll1.is_not_same(&Poll::Pending));
+    assert!(Poll::<f64>::Pending.is_same(&Poll::Pending));
+}
+
+#[test]
+fn check_fieldless_enums() {
+    use std::cmp::Ordering;
+    use std::fmt::Alignment;
+    use std::num::FpCategory;
+    use std::sync::atomic;
+
+    assert!(Ordering::Less.is_same(&1.cmp(&2)));
+    assert!(Ordering::Less.is_not_same(&Ordering::Greater));
+    assert!(Alignment::Left.is_same(&Alignment::Left));
+    assert!(Alignment::Left.is_not_same(&Alignment::Center));
+    assert!(f32::NAN.classify().is_same(&FpCategory::Nan));
+    assert!(1.0f32.classify().is_not_same(&FpCategory::Zero));
+    assert!(atomic::Ordering::SeqCst.is_same(&atomic::Ordering::SeqCst));
+    assert!(atomic::Ordering::SeqCst.is_not_same(&atomic::Ordering::Relaxed));
+
+    let err1 = "".parse::<u8>().unwrap_err();
+    let err2 = "1000".parse::<u8>().unwrap_err();
+    assert!(err1.kind().is_same(err1.kind()));
+    assert!(err1.kind().is_not_same(err2.kind()));
+}
+
+#[test]
+fn check_vec_deque() {
+    use std::collections::VecDeque;
+
+    let deque1: VecDeque<u8> = vec![1, 2, 3].into();
+    // Same contents, but wrapped around the ring buffer differently.
+    let mut deque2 = VecDeque::with_capacity(3);
+    deque2.push_back(2);
+    deque2.push_back(3);
+    deque2.push_front(1);
+    assert!(deque1.is_same(&deque2));
+    assert!(deque2.is_same(&deque1));
+    deque2.push_back(4);
+    assert!(deque1.is_not_same(&deque2));
+    deque2.pop_back();
+    deque2.pop_front();
+    deque2.push_front(0);
+    assert!(deque1.is_not_same(&deque2));
+}
+
+#[test]
+fn check_linked_list() {
+    use std::collections::LinkedList;
+
+    let list1: LinkedList<&str> = ["foo", "bar"].iter().copied().collect();
+    let mut list2 = LinkedList::new();
+    list2.push_back("bar");
+    list2.push_front("foo");
+    assert!(list1.is_same(&list2));
+    list2.push_back("baz");
+    assert!(list1.is_not_same(&list2));
+    assert!(list2.is_not_same(&list1));
+    list2.pop_back();
+    list2.pop_front();
+    list2.push_back("foo");
+    assert!(list1.is_not_same(&list2));
+}
+
+#[test]
+fn check_binary_heap() {
+    use std::cmp::Ordering;
+    use std::collections::BinaryHeap;
+
+    let heap1: BinaryHeap<u32> = vec![5, 1, 4, 2, 3, 4].into();
+    let heap2: BinaryHeap<u32> = vec![4, 4, 3, 2, 1, 5].into();
+    assert!(heap1.is_same(&heap2));
+    let heap2: BinaryHeap<u32> = vec![4, 3, 3, 2, 1, 5].into();
+    assert!(heap1.is_not_same(&heap2));
+    let heap2: BinaryHeap<u32> = vec![4, 4, 3, 2, 1].into();
+    assert!(heap1.is_not_same(&heap2));
+
+    // Ordered only by priority, so IsSame sees more than Ord does.
+    #[derive(Debug)]
+    struct Task(u8, &'static str);
+    impl PartialEq for Task {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    impl Eq for Task {}
+    impl PartialOrd for Task {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Task {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+    impl IsSame for Task {
+        fn is_same(&self, other: &Self) -> bool {
+            self.0 == other.0 && self.1 == other.1
+        }
+    }
+
+    let heap1: BinaryHeap<Task> = vec![Task(1, "a"), Task(1, "b"), Task(2, "c")].into();
+    let heap2: BinaryHeap<Task> = vec![Task(2, "c"), Task(1, "b"), Task(1, "a")].into();
+    assert!(heap1.is_same(&heap2));
+    let heap2: BinaryHeap<Task> = vec![Task(2, "c"), Task(1, "b"), Task(1, "b")].into();
+    assert!(heap1.is_not_same(&heap2));
+    let heap2: BinaryHeap<Task> = vec![Task(2, "c"), Task(2, "b"), Task(1, "a")].into();
+    assert!(heap1.is_not_same(&heap2));
+}
+
+#[test]
+fn check_large_arrays() {
+    let mut table1 = [0.0f32; 64];
+    let table2 = [0.0f32; 64];
+    assert!(table1.is_same(&table2));
+    table1[63] = -0.0;
+    assert!(table1.is_not_same(&table2));
+
+    let empty: [u8; 0] = [];
+    assert!(empty.is_same(&[]));
+}
+
+#[test]
+fn check_wide_tuples() {
+    let t1 = (
+        1u8, 2u16, 3u32, 4u64, 5i8, 6i16, 7i32, 8i64, 'a', "b", 1.0f32, 2.0f64,
+    );
+    let mut t2 = t1;
+    assert!(t1.is_same(&t1));
+    assert!(t1.is_same(&t2));
+    t2.11 = f64::NAN;
+    assert!(t1.is_not_same(&t2));
+
+    let t1 = (1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+    let mut t2 = t1;
+    assert!(t1.is_same(&t2));
+    t2.15 = 0;
+    assert!(t1.is_not_same(&t2));
+}
+
+#[test]
+fn check_fn_pointers() {
+    struct Event;
+
+    fn first(_: &Event) {}
+    fn second(event: &Event) {
+        std::hint::black_box(event);
+    }
+    fn add(a: u32, b: u32) -> u32 {
+        a + b
+    }
+    fn sub(a: u32, b: u32) -> u32 {
+        a - b
+    }
+    extern "C" fn callback(value: i32) -> i32 {
+        value
+    }
+
+    let handler1: fn(&Event) = first;
+    let handler2: fn(&Event) = second;
+    assert!(handler1.is_same(&handler1));
+    assert!(handler1.is_not_same(&handler2));
+    handler1(&Event);
+
+    let op1: fn(u32, u32) -> u32 = add;
+    let op2: fn(u32, u32) -> u32 = sub;
+    assert!(op1.is_same(&op1));
+    assert!(op1.is_not_same(&op2));
+    assert_eq!(op1(1, 2) + op2(2, 1), 4);
+
+    let ffi: extern "C" fn(i32) -> i32 = callback;
+    assert!(ffi.is_same(&ffi));
+    assert_eq!(ffi(1), 1);
+}
+
+#[test]
+fn check_raw_pointers() {
+    use std::ffi::c_void;
+    use std::ptr::{self, NonNull};
+
+    let mut values = [1u32, 1];
+    let ptr1: *const u32 = &values[0];
+    let ptr2: *const u32 = &values[1];
+    assert!(ptr1.is_same(&ptr1));
+    assert!(ptr1.is_not_same(&ptr2));
+    assert!(ptr::null::<c_void>().is_same(&ptr::null()));
+    assert!((ptr1 as *const c_void).is_not_same(&ptr::null()));
+
+    let slice1: *const [u32] = &values[..];
+    let slice2: *const [u32] = &values[..1];
+    assert!(slice1.is_same(&slice1));
+    assert!(slice1.is_not_same(&slice2));
+
+    let mut1: *mut u32 = &mut values[0];
+    assert!(mut1.is_same(&mut1));
+    assert!(mut1.is_not_same(&ptr::null_mut()));
+
+    let non_null1 = NonNull::from(&values[0]);
+    let non_null2 = NonNull::from(&values[1]);
+    assert!(non_null1.is_same(&NonNull::from(&values[0])));
+    assert!(non_null1.is_not_same(&non_null2));
+}
+
+#[test]
+fn check_owned_borrowed() {
+    let string = String::from("foo");
+    let other = String::from("bar");
+    assert!(string.is_same(&string));
+    assert!(string.is_not_same(&other));
+    assert!(string.is_same("foo"));
+    assert!(string.is_same(&"foo"));
+    assert!(string.is_not_same(&"bar"));
+    assert!("foo".is_same(&string));
+    assert!("bar".is_not_same(&string));
+
+    let vec = vec![1, 2, 3];
+    let slice: &[i32] = &[1, 2, 3];
+    let array = [1, 2, 3];
+    assert!(vec.is_same(slice));
+    assert!(vec.is_same(&array));
+    assert!(vec.is_not_same(&[1, 2]));
+    assert!(slice.is_same(&vec));
+    assert!(slice.is_same(&array));
+    assert!(array.is_same(&vec));
+    assert!(array.is_same(slice));
+    assert!(array.is_not_same(&[1, 2, 4]));
+}
+
+#[test]
+fn check_cross_type() {
+    use std::borrow::Cow;
+
+    let opt1: Option<Cow<'_, str>> = Some("foo".into());
+    assert!(opt1.is_same(&Some(String::from("foo"))));
+    assert!(opt1.is_not_same(&Some(String::from("bar"))));
+    assert!(opt1.is_not_same(&None::<String>));
+    assert!(None::<u8>.is_same(&None::<u8>));
+
+    let snapshot: Vec<Cow<'_, str>> = vec!["foo".into(), String::from("bar").into()];
+    let current = vec![String::from("foo"), String::from("bar")];
+    assert!(snapshot.is_same(&current));
+    assert!(current.is_same(&snapshot));
+    assert!(snapshot[..].is_same(&current));
+    assert!(snapshot.is_not_same(&current[..1]));
+    let array: [&str; 2] = ["foo", "baz"];
+    assert!(snapshot.is_not_same(&array));
+
+    let t1 = (Cow::Borrowed("foo"), 1u8);
+    assert!(t1.is_same(&(String::from("foo"), 1u8)));
+    assert!(t1.is_not_same(&(String::from("foo"), 2u8)));
+
+    // Slices that alias are the same without visiting the elements.
+    let vec = vec![f32::NAN; 4];
+    assert!(vec.is_same(&vec[..]));
+    // Zero-sized elements always share an address.
+    assert!(vec![(); 3].is_not_same(&vec![(); 5]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_different_hashers() {
+    use std::collections::hash_map::RandomState;
+    use std::collections::{HashMap, HashSet};
+    use std::hash::{BuildHasherDefault, DefaultHasher};
+
+    type FixedState = BuildHasherDefault<DefaultHasher>;
+
+    let mut map1: HashMap<&str, u8, RandomState> = HashMap::default();
+    map1.insert("foo", 1);
+    map1.insert("bar", 2);
+    let mut map2: HashMap<&str, u8, FixedState> = HashMap::default();
+    map2.insert("bar", 2);
+    map2.insert("foo", 1);
+    assert!(map1.is_same(&map2));
+    assert!(map2.is_same(&map1));
+    map2.insert("foo", 3);
+    assert!(map1.is_not_same(&map2));
+
+    let set1: HashSet<&str, RandomState> = ["foo", "bar"].iter().copied().collect();
+    let mut set2: HashSet<&str, FixedState> = ["bar", "foo"].iter().copied().collect();
+    assert!(set1.is_same(&set2));
+    assert!(set2.is_same(&set1));
+    set2.insert("baz");
+    assert!(set1.is_not_same(&set2));
+    set2.remove("foo");
+    set2.remove("baz");
+    set2.insert("buzz");
+    assert!(set1.is_not_same(&set2));
+}
+
+#[test]
+fn check_result() {
+    use std::convert::Infallible;
+
+    let ok1: Result<u8, Infallible> = Ok(1);
+    assert!(ok1.is_same(&Ok(1)));
+    assert!(ok1.is_not_same(&Ok(2)));
+
+    let res1: Result<String, String> = Err("bad".to_owned());
+    assert!(res1.is_same(&Err::<&str, &str>("bad")));
+    assert!(res1.is_not_same(&Err::<&str, &str>("worse")));
+    assert!(res1.is_not_same(&Ok::<&str, &str>("bad")));
+}
+
+#[test]
+fn check_pin() {
+    use std::pin::Pin;
+    use std::rc::Rc;
+
+    let boxed1 = Box::pin(vec![1, 2, 3]);
+    let boxed2 = Box::pin(vec![1, 2, 3]);
+    assert!(boxed1.is_same(&boxed1));
+    assert!(boxed1.is_same(&boxed2));
+    assert!(boxed1.is_not_same(&Box::pin(vec![1, 2])));
+
+    let rc1 = Rc::pin(f32::NAN);
+    let rc2 = rc1.clone();
+    assert!(rc1.is_same(&rc2));
+    assert!(rc1.is_not_same(&Rc::pin(1.0)));
+
+    let value = 4;
+    let pin: Pin<&i32> = Pin::new(&value);
+    assert!(pin.is_same(&Pin::new(&4)));
+    assert!(pin.is_same(&Box::pin(4)));
+}
+
+#[test]
+fn check_manually_drop() {
+    use std::mem::ManuallyDrop;
+
+    let value1 = ManuallyDrop::new(-0.0f64);
+    assert!(value1.is_same(&ManuallyDrop::new(-0.0)));
+    assert!(value1.is_not_same(&ManuallyDrop::new(0.0)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_io_error() {
+    use std::io::{Error, ErrorKind};
+
+    assert!(ErrorKind::NotFound.is_same(&ErrorKind::NotFound));
+    assert!(ErrorKind::NotFound.is_not_same(&ErrorKind::PermissionDenied));
+
+    let err1 = Error::other("disk on fire");
+    assert!(err1.is_same(&Error::other("disk on fire")));
+    assert!(err1.is_not_same(&Error::other("disk is fine")));
+    assert!(err1.is_not_same(&Error::new(ErrorKind::NotFound, "disk on fire")));
+
+    let os1 = Error::from_raw_os_error(2);
+    assert!(os1.is_same(&Error::from_raw_os_error(2)));
+    assert!(os1.is_not_same(&Error::from_raw_os_error(13)));
+    assert!(os1.is_not_same(&Error::from(os1.kind())));
+}
+
+#[test]
+fn check_identity_handles() {
+    use std::alloc::Layout;
+
+    assert!(Layout::new::<u32>().is_same(&Layout::new::<i32>()));
+    assert!(Layout::new::<u32>().is_not_same(&Layout::new::<u64>()));
+
+    #[cfg(feature = "std")]
+    {
+        use std::thread;
+
+        let main = thread::current().id();
+        let other = thread::spawn(|| thread::current().id()).join().unwrap();
+        assert!(main.is_same(&thread::current().id()));
+        assert!(main.is_not_same(&other));
+    }
+
+    #[cfg(all(feature = "std", unix))]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        use std::process::ExitStatus;
+
+        let status = ExitStatus::from_raw(0);
+        assert!(status.is_same(&ExitStatus::from_raw(0)));
+        assert!(status.is_not_same(&ExitStatus::from_raw(256)));
+    }
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn check_serde_json() {
+    use serde_json::json;
+
+    let doc1 = json!({
+        "name": "foo",
+        "tags": ["a", "b"],
+        "size": 1.5,
+        "count": 3,
+        "nested": { "zero": 0.0, "none": null },
+    });
+    let mut doc2 = doc1.clone();
+    assert!(doc1.is_same(&doc2));
+    doc2["nested"]["zero"] = json!(-0.0);
+    assert!(doc1.is_not_same(&doc2));
+    doc2["nested"]["zero"] = json!(0.0);
+    assert!(doc1.is_same(&doc2));
+    doc2["count"] = json!(3.0);
+    assert!(doc1.is_not_same(&doc2));
+    doc2["count"] = json!(3);
+    doc2["tags"][1] = json!("c");
+    assert!(doc1.is_not_same(&doc2));
+    doc2["tags"][1] = json!("b");
+    doc2["extra"] = json!(true);
+    assert!(doc1.is_not_same(&doc2));
+    assert!(doc1
+        .as_object()
+        .unwrap()
+        .is_not_same(doc2.as_object().unwrap()));
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn check_http() {
+    use http::header::{HeaderMap, HeaderValue, ACCEPT, HOST};
+    use http::{Method, StatusCode, Uri};
+
+    let mut headers1 = HeaderMap::new();
+    headers1.insert(HOST, HeaderValue::from_static("example.com"));
+    headers1.append(ACCEPT, HeaderValue::from_static("text/html"));
+    headers1.append(ACCEPT, HeaderValue::from_static("*/*"));
+    let mut headers2 = HeaderMap::new();
+    headers2.append(ACCEPT, HeaderValue::from_static("text/html"));
+    headers2.append(ACCEPT, HeaderValue::from_static("*/*"));
+    headers2.insert(HOST, HeaderValue::from_static("example.com"));
+    assert!(headers1.is_same(&headers2));
+
+    let mut reordered = HeaderMap::new();
+    reordered.insert(HOST, HeaderValue::from_static("example.com"));
+    reordered.append(ACCEPT, HeaderValue::from_static("*/*"));
+    reordered.append(ACCEPT, HeaderValue::from_static("text/html"));
+    assert!(headers1.is_not_same(&reordered));
+    headers2.append(ACCEPT, HeaderValue::from_static("*/*"));
+    assert!(headers1.is_not_same(&headers2));
+
+    assert!(Method::GET.is_same(&Method::GET));
+    assert!(Method::GET.is_not_same(&Method::POST));
+    assert!(StatusCode::OK.is_not_same(&StatusCode::NOT_FOUND));
+    let uri: Uri = "https://example.com/a?b".parse().unwrap();
+    assert!(uri.is_same(&"https://example.com/a?b".parse::<Uri>().unwrap()));
+    assert!(uri.is_not_same(&"https://example.com/a".parse::<Uri>().unwrap()));
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn check_toml() {
+    let doc1: toml::Table = toml::from_str(
+        r#"
+        name = "foo"
+        scale = 1.5
+        count = 3
+        [server]
+        ports = [80, 443]
+        timeout = 0.0
+        "#,
+    )
+    .unwrap();
+    let mut doc2 = doc1.clone();
+    assert!(doc1.is_same(&doc2));
+    doc2["server"]["timeout"] = toml::Value::Float(-0.0);
+    assert!(doc1.is_not_same(&doc2));
+    doc2["server"]["timeout"] = toml::Value::Float(0.0);
+    assert!(doc1["server"].is_same(&doc2["server"]));
+    doc2["count"] = toml::Value::Float(3.0);
+    assert!(doc1.is_not_same(&doc2));
+    doc2["count"] = toml::Value::Integer(3);
+    assert!(doc1.is_same(&doc2));
+    doc2.insert("extra".to_owned(), toml::Value::Boolean(true));
+    assert!(doc1.is_not_same(&doc2));
+
+    let nan = toml::Value::Float(f64::NAN);
+    assert!(nan.is_same(&nan.clone()));
+}
+
+#[cfg(feature = "serde_yaml")]
+#[test]
+fn check_serde_yaml() {
+    use serde_yaml::Value;
+
+    let doc1: Value = serde_yaml::from_str(
+        "
+        name: foo
+        sizes: [1, 2.5]
+        zero: 0.0
+        mode: !Fast 3
+        ",
+    )
+    .unwrap();
+    let mut doc2 = doc1.clone();
+    assert!(doc1.is_same(&doc2));
+    doc2["zero"] = Value::from(-0.0);
+    assert!(doc1.is_not_same(&doc2));
+    doc2["zero"] = Value::from(0.0);
+    assert!(doc1.is_same(&doc2));
+    doc2["sizes"][0] = Value::from(1.0);
+    assert!(doc1.is_not_same(&doc2));
+    doc2["sizes"][0] = Value::from(1);
+    doc2["mode"] = serde_yaml::from_str("!Slow 3").unwrap();
+    assert!(doc1.is_not_same(&doc2));
+
+    let reordered: Value = serde_yaml::from_str(
+        "
+        mode: !Fast 3
+        zero: 0.0
+        sizes: [1, 2.5]
+        name: foo
+        ",
+    )
+    .unwrap();
+    assert!(doc1.is_same(&reordered));
+
+    // Keys are compared with IsSame too, so a `0.0` key isn't the same as
+    // a `-0.0` key even though serde_yaml considers them equal.
+    let zero: Value = serde_yaml::from_str("0.0: a").unwrap();
+    let negative_zero: Value = serde_yaml::from_str("-0.0: a").unwrap();
+    assert!(zero.is_same(&zero.clone()));
+    assert_eq!(zero, negative_zero);
+    assert!(zero.is_not_same(&negative_zero));
+}
+
+#[cfg(feature = "indexmap")]
+#[test]
+fn check_indexmap() {
+    use indexmap::{IndexMap, IndexSet};
+
+    let mut map1 = IndexMap::new();
+    map1.insert("foo", 1.0);
+    map1.insert("bar", f64::NAN);
+    let mut map2 = map1.clone();
+    assert!(map1.is_same(&map2));
+    map2.insert("bar", 2.0);
+    assert!(map1.is_not_same(&map2));
+
+    // Same entries, different order.
+    let mut map2 = IndexMap::new();
+    map2.insert("bar", f64::NAN);
+    map2.insert("foo", 1.0);
+    assert!(map1.is_not_same(&map2));
+
+    let set1: IndexSet<&str> = ["foo", "bar"].iter().copied().collect();
+    let set2: IndexSet<&str> = ["foo", "bar"].iter().copied().collect();
+    assert!(set1.is_same(&set2));
+    let set2: IndexSet<&str> = ["bar", "foo"].iter().copied().collect();
+    assert!(set1.is_not_same(&set2));
+    let set2: IndexSet<&str> = ["foo"].iter().copied().collect();
+    assert!(set1.is_not_same(&set2));
+}
+
+#[cfg(feature = "im")]
+#[test]
+fn check_im() {
+    use im::{hashmap, hashset, ordmap, ordset, Vector};
+
+    let vec1: Vector<f32> = (0..1000).map(|i| i as f32).collect();
+    let mut vec2 = vec1.clone();
+    assert!(vec1.is_same(&vec2));
+    vec2.set(500, f32::NAN);
+    assert!(vec1.is_not_same(&vec2));
+    vec2.set(500, 500.0);
+    assert!(vec1.is_same(&vec2));
+    vec2.push_front(-1.0);
+    assert!(vec1.is_not_same(&vec2));
+    vec2.pop_front();
+    assert!(vec1.is_same(&vec2));
+    let vec3: Vector<f32> = (0..1000).map(|i| i as f32).collect();
+    assert!(vec1.is_same(&vec3));
+
+    // A NaN compared with `==` is never the same as itself, so this only
+    // passes if the shared chunk holding it is skipped by its pointer.
+    #[derive(Clone, PartialEq)]
+    struct ByEq(f32);
+    is_same::impl_is_same_via_partial_eq!(ByEq);
+
+    let vec1: Vector<ByEq> = (0..1000)
+        .map(|i| ByEq(if i == 0 { f32::NAN } else { i as f32 }))
+        .collect();
+    let mut vec2 = vec1.clone();
+    vec2.set(999, ByEq(999.0));
+    assert!(!vec1.ptr_eq(&vec2));
+    assert!(vec1.is_same(&vec2));
+    vec2.set(0, ByEq(f32::NAN));
+    assert!(vec1.is_not_same(&vec2));
+
+    let map1 = hashmap! { "foo" => 1, "bar" => 2 };
+    let mut map2 = map1.clone();
+    assert!(map1.is_same(&map2));
+    map2.insert("bar", 3);
+    assert!(map1.is_not_same(&map2));
+    assert!(map1.is_same(&hashmap! { "bar" => 2, "foo" => 1 }));
+
+    let set1 = hashset! { "foo", "bar" };
+    assert!(set1.is_same(&set1.clone()));
+    assert!(set1.is_same(&hashset! { "bar", "foo" }));
+    assert!(set1.is_not_same(&hashset! { "bar" }));
+
+    let map1 = ordmap! { 1 => "foo", 2 => "bar" };
+    assert!(map1.is_same(&map1.clone()));
+    assert!(map1.is_same(&ordmap! { 2 => "bar", 1 => "foo" }));
+    assert!(map1.is_not_same(&ordmap! { 2 => "baz", 1 => "foo" }));
+
+    let set1 = ordset! { 1, 2, 3 };
+    assert!(set1.is_same(&set1.clone()));
+    assert!(set1.is_same(&ordset! { 3, 2, 1 }));
+    assert!(set1.is_not_same(&ordset! { 3, 2 }));
+
+    // Keys are equal but live in separate allocations, so they aren't
+    // the same.
+    let key: std::rc::Rc<str> = "a".into();
+    let other_key: std::rc::Rc<str> = "a".into();
+    assert!(hashmap! { key.clone() => 1 }.is_same(&hashmap! { key.clone() => 1 }));
+    assert!(hashmap! { key.clone() => 1 }.is_not_same(&hashmap! { other_key.clone() => 1 }));
+    assert!(hashset! { key.clone() }.is_same(&hashset! { key.clone() }));
+    assert!(hashset! { key.clone() }.is_not_same(&hashset! { other_key.clone() }));
+    assert!(ordmap! { key.clone() => 1 }.is_same(&ordmap! { key.clone() => 1 }));
+    assert!(ordmap! { key.clone() => 1 }.is_not_same(&ordmap! { other_key.clone() => 1 }));
+    assert!(ordset! { key.clone() }.is_same(&ordset! { key.clone() }));
+    assert!(ordset! { key }.is_not_same(&ordset! { other_key }));
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn check_bytes() {
+    use bytes::{Bytes, BytesMut};
+
+    let buffer = Bytes::from(vec![1u8, 2, 3, 4, 5, 6]);
+    let slice1 = buffer.slice(0..3);
+    let slice2 = buffer.slice(0..3);
+    assert!(slice1.is_same(&slice2));
+    assert!(slice1.is_not_same(&buffer.slice(0..4)));
+    assert!(slice1.is_not_same(&buffer.slice(3..6)));
+    assert!(slice1.is_same(&Bytes::from_static(&[1, 2, 3])));
+    assert!(slice1.is_same(&[1u8, 2, 3]));
+    assert!(slice1.is_same(&vec![1u8, 2, 3]));
+
+    let mut bytes_mut = BytesMut::new();
+    bytes_mut.extend_from_slice(&[1, 2, 3]);
+    assert!(bytes_mut.is_same(&slice1));
+    assert!(slice1.is_same(&bytes_mut));
+    bytes_mut.extend_from_slice(&[4]);
+    assert!(bytes_mut.is_not_same(&slice1));
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn check_uuid() {
+    use uuid::Uuid;
+
+    let id1 = Uuid::from_u128(0x1234);
+    assert!(id1.is_same(&Uuid::from_u128(0x1234)));
+    assert!(id1.is_not_same(&Uuid::nil()));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn check_chrono() {
+    use chrono::{FixedOffset, NaiveDate, TimeDelta, TimeZone, Utc, Weekday};
+
+    let date1 = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+    assert!(date1.is_same(&NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()));
+    assert!(date1.is_not_same(&NaiveDate::from_ymd_opt(2020, 3, 1).unwrap()));
+    let datetime1 = date1.and_hms_opt(12, 0, 0).unwrap();
+    assert!(datetime1.is_same(&date1.and_hms_opt(12, 0, 0).unwrap()));
+    assert!(datetime1
+        .time()
+        .is_not_same(&date1.and_hms_opt(12, 0, 1).unwrap().time()));
+
+    let utc1 = Utc.from_utc_datetime(&datetime1);
+    assert!(utc1.is_same(&Utc.from_utc_datetime(&datetime1)));
+    assert!(utc1.is_not_same(&(utc1 + TimeDelta::seconds(1))));
+
+    // The same instant in different offsets.
+    let plus_one = FixedOffset::east_opt(3600).unwrap();
+    let zero = FixedOffset::east_opt(0).unwrap();
+    let offset1 = plus_one.from_utc_datetime(&datetime1);
+    let offset2 = zero.from_utc_datetime(&datetime1);
+    assert_eq!(offset1, offset2);
+    assert!(offset1.is_same(&plus_one.from_utc_datetime(&datetime1)));
+    assert!(offset1.is_not_same(&offset2));
+
+    assert!(TimeDelta::minutes(1).is_same(&TimeDelta::seconds(60)));
+    assert!(Weekday::Mon.is_not_same(&Weekday::Tue));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn check_time_crate() {
+    use time::{Date, Duration, Month, Time, UtcOffset};
+
+    let date1 = Date::from_calendar_date(2020, Month::February, 29).unwrap();
+    assert!(date1.is_same(&Date::from_calendar_date(2020, Month::February, 29).unwrap()));
+    assert!(date1.is_not_same(&date1.next_day().unwrap()));
+    let time1 = Time::from_hms(12, 0, 0).unwrap();
+    assert!(time1.is_same(&Time::from_hms(12, 0, 0).unwrap()));
+    let datetime1 = date1.with_time(time1);
+    assert!(datetime1.is_same(&date1.with_time(time1)));
+    assert!(datetime1.is_not_same(&(datetime1 + Duration::seconds(1))));
+
+    // The same instant in different offsets.
+    let utc1 = datetime1.assume_utc();
+    let offset1 = utc1.to_offset(UtcOffset::from_hms(1, 0, 0).unwrap());
+    assert_eq!(utc1, offset1);
+    assert!(utc1.is_same(&datetime1.assume_utc()));
+    assert!(utc1.is_not_same(&offset1));
+
+    assert!(Duration::minutes(1).is_same(&Duration::seconds(60)));
+    assert!(UtcOffset::UTC.is_not_same(&UtcOffset::from_hms(1, 0, 0).unwrap()));
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn check_rust_decimal() {
+    use rust_decimal::Decimal;
+
+    let price1 = Decimal::new(150, 2);
+    assert!(price1.is_same(&Decimal::new(150, 2)));
+    assert!(price1.is_not_same(&Decimal::new(151, 2)));
+    // Equal, but with a different scale.
+    let price2 = Decimal::new(15, 1);
+    assert_eq!(price1, price2);
+    assert!(price1.is_not_same(&price2));
+    let zero = Decimal::ZERO;
+    assert!(zero.is_not_same(&-zero));
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn check_num_bigint() {
+    use num_bigint::{BigInt, BigUint};
+
+    let big1 = BigUint::from(u64::MAX) * BigUint::from(u64::MAX);
+    assert!(big1.is_same(&(BigUint::from(u64::MAX) * BigUint::from(u64::MAX))));
+    assert!(big1.is_not_same(&BigUint::from(1u8)));
+    let neg = -BigInt::from(5);
+    assert!(neg.is_same(&BigInt::from(-5)));
+    assert!(neg.is_not_same(&BigInt::from(5)));
+    assert!(neg.sign().is_not_same(&BigInt::from(5).sign()));
+}
+
+#[cfg(feature = "num-rational")]
+#[test]
+fn check_num_rational() {
+    use num_rational::Ratio;
+
+    let half = Ratio::new(1, 2);
+    assert!(half.is_same(&Ratio::new(2, 4)));
+    assert!(half.is_not_same(&Ratio::new(1, 3)));
+    // Not reduced, so not the same even though it's equal.
+    let raw = Ratio::new_raw(2, 4);
+    assert_eq!(half, raw);
+    assert!(half.is_not_same(&raw));
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn check_half() {
+    use half::{bf16, f16};
+
+    assert!(f16::from_f32(1.5).is_same(&f16::from_f32(1.5)));
+    assert!(f16::NAN.is_same(&f16::NAN));
+    assert!(f16::ZERO.is_not_same(&f16::NEG_ZERO));
+    assert!(bf16::from_f32(1.5).is_same(&bf16::from_f32(1.5)));
+    assert!(bf16::NAN.is_same(&bf16::NAN));
+    assert!(bf16::ZERO.is_not_same(&bf16::NEG_ZERO));
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn check_either() {
+    use either::Either;
+
+    let left: Either<f32, String> = Either::Left(f32::NAN);
+    let right: Either<f32, String> = Either::Right("foo".to_owned());
+    assert!(left.is_same(&left));
+    assert!(left.is_not_same(&right));
+    assert!(left.is_not_same(&Either::<f32, String>::Left(1.0)));
+    assert!(right.is_same(&Either::<f32, &str>::Right("foo")));
+    assert!(right.is_not_same(&Either::<f32, &str>::Right("bar")));
+}
+
+#[cfg(feature = "url")]
+#[test]
+fn check_url() {
+    use url::Url;
+
+    let url = Url::parse("https://example.com/foo?bar").unwrap();
+    assert!(url.is_same(&Url::parse("HTTPS://example.com/foo?bar").unwrap()));
+    assert!(url.is_not_same(&Url::parse("https://example.com/foo").unwrap()));
+    assert!(url.is_same("https://example.com/foo?bar"));
+    assert!("https://example.com/foo?bar".is_same(&url));
+    assert!(url.is_not_same("https://example.com/foo"));
+}
+
+#[cfg(feature = "semver")]
+#[test]
+fn check_semver() {
+    use semver::{Version, VersionReq};
+
+    let version = Version::parse("1.2.3-alpha+build.1").unwrap();
+    assert!(version.is_same(&Version::parse("1.2.3-alpha+build.1").unwrap()));
+    assert!(version.is_not_same(&Version::parse("1.2.3-alpha+build.2").unwrap()));
+    assert!(version
+        .pre
+        .is_same(&Version::parse("1.0.0-alpha").unwrap().pre));
+    let req = VersionReq::parse(">=1.2, <2").unwrap();
+    assert!(req.is_same(&VersionReq::parse(">=1.2, <2").unwrap()));
+    assert!(req.is_not_same(&VersionReq::parse(">=1.3, <2").unwrap()));
+}
+
+#[cfg(feature = "camino")]
+#[test]
+fn check_camino() {
+    use camino::{Utf8Path, Utf8PathBuf};
+
+    let path = Utf8PathBuf::from("foo/bar");
+    assert!(path.is_same(&Utf8PathBuf::from("foo/bar")));
+    assert!(path.is_same(Utf8Path::new("foo//bar")));
+    assert!(path.is_same("foo/bar"));
+    assert!(path.is_not_same("foo/baz"));
+    assert!(Utf8Path::new("foo/bar").is_same(&path));
+    assert!(path.as_path().is_not_same(&Utf8PathBuf::from("foo")));
+}
+
+#[cfg(feature = "compact_str")]
+#[test]
+fn check_compact_str() {
+    use compact_str::CompactString;
+
+    let string = CompactString::from("foo");
+    assert!(string.is_same(&CompactString::from("foo")));
+    assert!(string.is_not_same(&CompactString::from("bar")));
+    assert!(string.is_same("foo"));
+    assert!("foo".to_owned().is_same(&string));
+}
+
+#[cfg(feature = "smol_str")]
+#[test]
+fn check_smol_str() {
+    use smol_str::SmolStr;
+
+    let short = SmolStr::new("foo");
+    assert!(short.is_same(&SmolStr::new("foo")));
+    assert!(short.is_not_same(&SmolStr::new("bar")));
+    assert!(short.is_same("foo"));
+    assert!("foo".is_same(&short));
+
+    let long = SmolStr::new("a string that is too long to be stored inline");
+    assert!(long.is_heap_allocated());
+    assert!(long.is_same(&long.clone()));
+    assert!(long.is_same(&SmolStr::new(long.as_str())));
+    assert!(long.is_not_same(&short));
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn check_glam() {
+    use glam::{Affine3A, IVec2, Mat4, Quat, Vec3};
+
+    let vec = Vec3::new(1.0, f32::NAN, 0.0);
+    assert!(vec.is_same(&Vec3::new(1.0, f32::NAN, 0.0)));
+    assert!(vec.is_not_same(&Vec3::new(1.0, f32::NAN, -0.0)));
+    assert!(Quat::IDENTITY.is_same(&Quat::IDENTITY));
+    assert!(Quat::IDENTITY.is_not_same(&Quat::from_rotation_x(1.0)));
+    let mat = Mat4::from_translation(vec);
+    assert!(mat.is_same(&Mat4::from_translation(vec)));
+    assert!(mat.is_not_same(&Mat4::IDENTITY));
+    let affine = Affine3A::from_translation(vec);
+    assert!(affine.is_same(&Affine3A::from_translation(vec)));
+    assert!(affine.is_not_same(&Affine3A::IDENTITY));
+    assert!(IVec2::new(1, 2).is_same(&IVec2::new(1, 2)));
+    assert!(IVec2::new(1, 2).is_not_same(&IVec2::new(2, 1)));
+}
+
+#[cfg(feature = "euclid")]
+#[test]
+fn check_euclid() {
+    use euclid::default::{Point2D, Rect, Size2D, Transform2D};
+
+    let rect = |x, height| Rect::new(Point2D::new(x, f32::NAN), Size2D::new(10.0, height));
+    assert!(rect(0.0, 5.0).is_same(&rect(0.0, 5.0)));
+    assert!(rect(0.0, 5.0).is_not_same(&rect(-0.0, 5.0)));
+    assert!(rect(0.0, 5.0).is_not_same(&rect(0.0, 6.0)));
+    let transform = Transform2D::translation(1.0, 2.0);
+    assert!(transform.is_same(&Transform2D::translation(1.0, 2.0)));
+    assert!(transform.is_not_same(&Transform2D::identity()));
+}
+
+#[cfg(feature = "kurbo")]
+#[test]
+fn check_kurbo() {
+    use kurbo::{Affine, BezPath, Point, Rect};
+
+    let rect = Rect::new(0.0, f64::NAN, 10.0, 5.0);
+    assert!(rect.is_same(&Rect::new(0.0, f64::NAN, 10.0, 5.0)));
+    assert!(rect.is_not_same(&Rect::new(-0.0, f64::NAN, 10.0, 5.0)));
+    assert!(Affine::scale(2.0).is_same(&Affine::scale(2.0)));
+    assert!(Affine::scale(2.0).is_not_same(&Affine::IDENTITY));
+
+    let mut path = BezPath::new();
+    path.move_to(Point::new(0.0, 0.0));
+    path.quad_to((1.0, 1.0), (2.0, 0.0));
+    path.close_path();
+    let mut other = path.clone();
+    assert!(path.is_same(&other));
+    other.line_to((0.0, 0.0));
+    assert!(path.is_not_same(&other));
+    let mut moved = BezPath::new();
+    moved.move_to(Point::new(0.0, -0.0));
+    moved.quad_to((1.0, 1.0), (2.0, 0.0));
+    moved.close_path();
+    assert!(path.is_not_same(&moved));
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn check_nalgebra() {
+    use nalgebra::{
+        DMatrix, Isometry3, Matrix2, Point3, Translation3, UnitQuaternion, Vector2, Vector3,
+    };
+
+    let vec = Vector3::new(1.0, f64::NAN, 0.0);
+    assert!(vec.is_same(&Vector3::new(1.0, f64::NAN, 0.0)));
+    assert!(vec.is_not_same(&Vector3::new(1.0, f64::NAN, -0.0)));
+
+    let mat = Matrix2::new(1.0, 2.0, 3.0, 4.0);
+    let dynamic = DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]);
+    assert!(mat.is_same(&dynamic));
+    assert!(mat.column(1).is_same(&Vector2::new(2.0, 4.0)));
+    assert!(mat.is_not_same(&mat.transpose()));
+    assert!(dynamic.is_not_same(&DMatrix::from_row_slice(1, 4, &[1.0, 2.0, 3.0, 4.0])));
+
+    assert!(Point3::new(1.0, 2.0, 3.0).is_same(&Point3::new(1.0, 2.0, 3.0)));
+    assert!(Point3::new(1.0, 2.0, 3.0).is_not_same(&Point3::origin()));
+
+    let rotation = UnitQuaternion::from_euler_angles(0.1, 0.2, 0.3);
+    let isometry = Isometry3::from_parts(Translation3::new(1.0, 2.0, 3.0), rotation);
+    assert!(isometry.is_same(&isometry.clone()));
+    assert!(isometry.is_not_same(&Isometry3::identity()));
+    assert!(rotation.is_not_same(&UnitQuaternion::identity()));
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn check_ndarray() {
+    use ndarray::{arr1, arr2, s, ArcArray, Array2, Ix2};
+
+    let array = arr2(&[[1.0, f64::NAN], [3.0, 4.0]]);
+    assert!(array.is_same(&arr2(&[[1.0, f64::NAN], [3.0, 4.0]])));
+    assert!(array.is_not_same(&arr2(&[[1.0, f64::NAN], [3.0, 5.0]])));
+    assert!(array.is_not_same(&array.t()));
+    assert!(array.row(1).is_same(&arr1(&[3.0, 4.0])));
+    assert!(array.is_same(&array.view().into_dyn()));
+    assert!(array.is_not_same(&Array2::<f64>::zeros((4, 1))));
+    assert!(array.slice(s![.., 0]).is_not_same(&array.slice(s![0, ..])));
+
+    let shared: ArcArray<f64, Ix2> = array.into_shared();
+    assert!(shared.is_same(&shared.clone()));
+}
+
+#[cfg(feature = "petgraph")]
+#[test]
+fn check_petgraph() {
+    use petgraph::graph::{Graph, NodeIndex};
+    use petgraph::stable_graph::StableGraph;
+
+    let mut graph = Graph::<&str, f32>::new();
+    let a = graph.add_node("a");
+    let b = graph.add_node("b");
+    graph.add_edge(a, b, f32::NAN);
+    assert!(graph.is_same(&graph.clone()));
+    let mut reversed = Graph::<&str, f32>::new();
+    let b2 = reversed.add_node("b");
+    let a2 = reversed.add_node("a");
+    reversed.add_edge(a2, b2, f32::NAN);
+    assert!(graph.is_not_same(&reversed));
+    let mut other = graph.clone();
+    other.add_edge(b, a, 1.0);
+    assert!(graph.is_not_same(&other));
+
+    let mut stable = StableGraph::<&str, f32>::new();
+    let a = stable.add_node("a");
+    let b = stable.add_node("b");
+    let c = stable.add_node("c");
+    stable.add_edge(a, c, 1.0);
+    let mut removed = stable.clone();
+    removed.remove_node(b);
+    assert!(stable.is_not_same(&removed));
+    stable.remove_node(b);
+    assert!(stable.is_same(&removed));
+    stable.add_node("b");
+    assert!(stable.is_not_same(&removed));
+
+    assert!(NodeIndex::<u32>::new(1).is_same(&NodeIndex::new(1)));
+    assert!(NodeIndex::<u32>::new(1).is_not_same(&NodeIndex::new(2)));
+}
+
+#[cfg(feature = "generational-arena")]
+#[test]
+fn check_generational_arena() {
+    use generational_arena::Arena;
+
+    let mut arena = Arena::new();
+    let a = arena.insert(f32::NAN);
+    arena.insert(2.0);
+    assert!(arena.is_same(&arena.clone()));
+    let mut other = arena.clone();
+    other[a] = 1.0;
+    assert!(arena.is_not_same(&other));
+
+    // Same value in the same slot, but a different generation.
+    let mut reinserted = arena.clone();
+    reinserted.remove(a);
+    let b = reinserted.insert(f32::NAN);
+    assert!(a.is_not_same(&b));
+    assert!(arena.is_not_same(&reinserted));
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn check_regex() {
+    use regex::{bytes, Regex, RegexSet};
+
+    let regex = Regex::new("a+").unwrap();
+    assert!(regex.is_same(&Regex::new("a+").unwrap()));
+    assert!(regex.is_not_same(&Regex::new("aa*").unwrap()));
+    assert!(bytes::Regex::new("a+")
+        .unwrap()
+        .is_same(&bytes::Regex::new("a+").unwrap()));
+    let set = RegexSet::new(["a", "b"]).unwrap();
+    assert!(set.is_same(&RegexSet::new(["a", "b"]).unwrap()));
+    assert!(set.is_not_same(&RegexSet::new(["b", "a"]).unwrap()));
+    assert!(bytes::RegexSet::new(["a"])
+        .unwrap()
+        .is_not_same(&bytes::RegexSet::new(["b"]).unwrap()));
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn check_heapless() {
+    use heapless::{Deque, FnvIndexMap, FnvIndexSet, String, Vec};
+    use std::convert::TryFrom;
+
+    let vec = Vec::<f32, 4>::from_slice(&[1.0, f32::NAN]).unwrap();
+    assert!(vec.is_same(&Vec::<f32, 8>::from_slice(&[1.0, f32::NAN]).unwrap()));
+    assert!(vec.is_same(&[1.0, f32::NAN]));
+    assert!(vec.is_not_same(&[1.0][..]));
+
+    let string = String::<8>::try_from("foo").unwrap();
+    assert!(string.is_same(&String::<4>::try_from("foo").unwrap()));
+    assert!(string.is_same("foo"));
+    assert!(string.is_not_same("bar"));
+
+    let mut deque = Deque::<u8, 4>::new();
+    deque.push_back(1).unwrap();
+    deque.push_front(0).unwrap();
+    let mut other = Deque::<u8, 2>::new();
+    other.push_back(0).unwrap();
+    other.push_back(1).unwrap();
+    assert!(deque.is_same(&other));
+    other.pop_back();
+    assert!(deque.is_not_same(&other));
+
+    let mut map = FnvIndexMap::<&str, f32, 4>::new();
+    map.insert("a", f32::NAN).unwrap();
+    map.insert("b", 2.0).unwrap();
+    let mut reordered = FnvIndexMap::<&str, f32, 4>::new();
+    reordered.insert("b", 2.0).unwrap();
+    reordered.insert("a", f32::NAN).unwrap();
+    assert!(map.is_same(&map.clone()));
+    assert!(map.is_not_same(&reordered));
+
+    let mut set = FnvIndexSet::<u8, 4>::new();
+    set.insert(1).unwrap();
+    assert!(set.is_same(&set.clone()));
+    assert!(set.is_not_same(&FnvIndexSet::<u8, 4>::new()));
+}
+
+mod foreign {
+    #[derive(PartialEq)]
+    pub struct Handle(pub u32);
+
+    #[derive(PartialEq)]
+    pub enum Mode {
+        Read,
+        Write,
+    }
+}
+
+is_same::impl_is_same_via_partial_eq!(foreign::Handle, foreign::Mode,);
+
+#[cfg(feature = "wide")]
+#[test]
+fn check_wide() {
+    use wide::{f32x4, u8x16};
+
+    let lanes = f32x4::new([1.0, f32::NAN, 0.0, 2.0]);
+    assert!(lanes.is_same(&f32x4::new([1.0, f32::NAN, 0.0, 2.0])));
+    assert!(lanes.is_not_same(&f32x4::new([1.0, f32::NAN, -0.0, 2.0])));
+    let mut many = vec![lanes; 8];
+    let copy = many.clone();
+    assert!(many.is_same(&copy));
+    many[7] = f32x4::new([1.0, f32::NAN, -0.0, 2.0]);
+    assert!(many.is_not_same(&copy));
+    assert!(u8x16::splat(1).is_same(&u8x16::splat(1)));
+    assert!(u8x16::splat(1).is_not_same(&u8x16::splat(2)));
+}
+
+#[cfg(feature = "portable_simd")]
+#[test]
+fn check_portable_simd() {
+    use std::simd::{f32x4, i32x4, Mask};
+
+    let lanes = f32x4::from_array([1.0, f32::NAN, 0.0, 2.0]);
+    assert!(lanes.is_same(&f32x4::from_array([1.0, f32::NAN, 0.0, 2.0])));
+    assert!(lanes.is_not_same(&f32x4::from_array([1.0, f32::NAN, -0.0, 2.0])));
+    assert!(i32x4::splat(3).is_same(&i32x4::splat(3)));
+    let mask = Mask::<i32, 4>::from_array([true, false, true, false]);
+    assert!(mask.is_same(&mask));
+    assert!(mask.is_not_same(&!mask));
+}
+
+#[test]
+fn check_impl_via_partial_eq() {
+    use foreign::{Handle, Mode};
+
+    assert!(Handle(1).is_same(&Handle(1)));
+    assert!(Handle(1).is_not_same(&Handle(2)));
+    assert!(Mode::Read.is_same(&Mode::Read));
+    assert!(Mode::Read.is_not_same(&Mode::Write));
+    assert!([Handle(1), Handle(2)].is_same(&[Handle(1), Handle(2)]));
+    assert!(vec![Handle(1)].is_not_same(&vec![Handle(2)]));
+}
+
+struct Meters(f64);
+struct Label(String);
+
+is_same::impl_is_same_newtype!(Meters => f64, Label => String);
+
+#[test]
+fn check_impl_newtype() {
+    assert!(Meters(f64::NAN).is_same(&Meters(f64::NAN)));
+    assert!(Meters(0.0).is_not_same(&Meters(-0.0)));
+    assert!(Meters(1.0).is_same(&1.0));
+    assert!(Meters(1.0).is_not_same(&2.0));
+    assert!(Label("foo".to_owned()).is_same(&Label("foo".to_owned())));
+    assert!(Label("foo".to_owned()).is_same(&"foo".to_owned()));
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn check_par_is_same() {
+    use is_same::parallel::ParIsSame;
+    use std::collections::HashMap;
+
+    let left = (0..100_000).map(|i| i as f32).collect::<Vec<_>>();
+    let mut right = left.clone();
+    assert!(left.par_is_same(&right));
+    assert!(left.par_is_same(&left));
+    right[50_000] = f32::NAN;
+    assert!(!left.par_is_same(&right));
+    assert!(left[..10].par_is_same(&right[..10]));
+    assert!(!left[..].par_is_same(&right[..10]));
+
+    let map = (0..1000).map(|i| (i, i as f64)).collect::<HashMap<_, _>>();
+    let mut other = map.clone();
+    assert!(map.par_is_same(&other));
+    other.insert(5, -0.0);
+    assert!(!map.par_is_same(&other));
+}
@@ -0,0 +1,63 @@
+#![cfg(any(feature = "testing", feature = "proptest"))]
+
+use is_same::testing::{check_matches_eq, check_reflexive, check_symmetric, check_transitive};
+use is_same::IsSame;
+
+#[derive(Debug)]
+struct Lopsided(u8, u8);
+
+// Compares the wrong fields, so it isn't symmetric.
+impl IsSame for Lopsided {
+    fn is_same(&self, other: &Self) -> bool {
+        self.0 == other.1
+    }
+}
+
+#[test]
+fn lawful() {
+    check_reflexive(&f64::NAN);
+    check_reflexive("foo");
+    check_symmetric(&vec![1, 2], &[1, 2]);
+    check_symmetric(&0.0, &-0.0);
+    check_transitive(&1, &1, &1);
+    check_matches_eq(&1, &2);
+    check_matches_eq("foo", "foo");
+}
+
+#[test]
+#[should_panic(expected = "IsSame is not reflexive")]
+fn not_reflexive() {
+    check_reflexive(&Lopsided(1, 2));
+}
+
+#[test]
+#[should_panic(expected = "IsSame is not symmetric")]
+fn not_symmetric() {
+    check_symmetric(&Lopsided(1, 2), &Lopsided(3, 1));
+}
+
+#[test]
+#[should_panic(expected = "IsSame doesn't match PartialEq")]
+fn float_not_eq() {
+    check_matches_eq(&f64::NAN, &f64::NAN);
+}
+
+#[cfg(feature = "proptest")]
+mod laws {
+    use super::Lopsided;
+    use is_same::testing::{check_laws, check_laws_against_eq};
+    use proptest::prelude::*;
+
+    #[test]
+    fn generated() {
+        check_laws(any::<f64>());
+        check_laws(proptest::collection::vec(0..3u8, 0..3));
+        check_laws_against_eq(any::<(u8, bool)>());
+    }
+
+    #[test]
+    #[should_panic(expected = "IsSame is not")]
+    fn generated_lopsided() {
+        check_laws((0..3u8, 0..3u8).prop_map(|(a, b)| Lopsided(a, b)));
+    }
+}
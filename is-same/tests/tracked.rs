@@ -0,0 +1,62 @@
+use is_same::tracked::Tracked;
+use is_same::IsSame;
+
+use std::cell::Cell;
+
+// Counts how often the contents get compared, to tell the comparisons
+// answered by the id apart from the ones that fell back to the value.
+#[derive(Clone)]
+struct Counted<'a> {
+    value: u32,
+    compared: &'a Cell<usize>,
+}
+
+impl IsSame for Counted<'_> {
+    fn is_same(&self, other: &Self) -> bool {
+        self.compared.set(self.compared.get() + 1);
+        self.value == other.value
+    }
+}
+
+#[test]
+fn check_tracked() {
+    let compared = Cell::new(0);
+    let counted = |value| Counted {
+        value,
+        compared: &compared,
+    };
+
+    let mut tracked = Tracked::new(counted(1));
+    let snapshot = tracked.clone();
+    assert!(!tracked.is_dirty());
+    assert!(tracked.is_same(&snapshot));
+    assert_eq!(compared.get(), 0);
+    // A separately created value has its own id.
+    assert!(tracked.is_same(&Tracked::new(counted(1))));
+    assert_eq!(compared.get(), 1);
+
+    tracked.get_mut().value = 2;
+    assert!(tracked.is_dirty());
+    assert!(tracked.is_not_same(&snapshot));
+    assert!(tracked.is_same(&tracked));
+    assert_eq!(compared.get(), 3);
+    tracked.clear_dirty();
+    assert!(tracked.is_not_same(&snapshot));
+    let snapshot = tracked.clone();
+    assert!(tracked.is_same(&snapshot));
+    assert_eq!(compared.get(), 4);
+
+    let mut current = Tracked::new(vec![1, 2]);
+    let snapshot = current.clone();
+    assert!(current.is_same(&snapshot));
+    current.push(3);
+    assert!(current.is_dirty());
+    assert!(current.is_not_same(&snapshot));
+    current.clear_dirty();
+    assert!(!current.is_dirty());
+    assert!(current.is_not_same(&snapshot));
+    current.pop();
+    current.clear_dirty();
+    assert!(current.is_same(&snapshot));
+    assert_eq!(current.into_inner(), vec![1, 2]);
+}
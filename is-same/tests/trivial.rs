@@ -0,0 +1,20 @@
+use is_same::trivial::is_same_bytes;
+use is_same::IsSame;
+
+#[test]
+fn same_bytes() {
+    assert!(is_same_bytes(&[1u32, 2, 3], &[1, 2, 3]));
+    assert!(!is_same_bytes(&[1u32, 2, 3], &[1, 2, 4]));
+    assert!(is_same_bytes::<u8>(&[], &[]));
+    assert!(is_same_bytes(&[[0u16; 4]; 2], &[[0; 4]; 2]));
+}
+
+#[test]
+fn float_slices() {
+    let left = vec![0.5f64, f64::NAN, 2.0];
+    let mut right = left.clone();
+    assert!(left.is_same(&right));
+    right[0] = -0.5;
+    assert!(left.is_not_same(&right));
+    assert!(vec![0.0f32].is_not_same(&vec![-0.0f32]));
+}
@@ -0,0 +1,37 @@
+use is_same::versioned::Versioned;
+use is_same::IsSame;
+
+struct Unequal;
+
+// Never the same, so any comparison that returns true must have come
+// from the version check.
+impl IsSame for Unequal {
+    fn is_same(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn check_versioned() {
+    let old = Versioned::new(Unequal);
+    assert!(old.is_same(&old));
+    assert!(old.is_not_same(&Versioned::new(Unequal)));
+
+    let old = Versioned::new(vec![1.0, f32::NAN]);
+    let mut new = old.clone();
+    assert_eq!(old.version(), new.version());
+    assert!(old.is_same(&new));
+
+    new.push(2.0);
+    assert_ne!(old.version(), new.version());
+    assert!(old.is_not_same(&new));
+    new.pop();
+    // Different versions, but the contents are the same again.
+    assert!(old.is_same(&new));
+
+    let version = new.version();
+    let _ = new.get_mut();
+    assert_ne!(version, new.version());
+    let _ = new.len();
+    assert_eq!(new.into_inner().len(), 2);
+}